@@ -136,6 +136,7 @@ distr_int!(distr_uniform_i16, i16, Uniform::new(-500i16, 2000));
 distr_int!(distr_uniform_i32, i32, Uniform::new(-200_000_000i32, 800_000_000));
 distr_int!(distr_uniform_i64, i64, Uniform::new(3i64, 123_456_789_123));
 distr_int!(distr_uniform_i128, i128, Uniform::new(-123_456_789_123i128, 123_456_789_123_456_789));
+distr_int!(distr_uniform_u128_narrow, u128, Uniform::new(3u128, 123_456_789_123));
 distr_int!(distr_uniform_usize16, usize, Uniform::new(0usize, 0xb9d7));
 distr_int!(distr_uniform_usize32, usize, Uniform::new(0usize, 0x548c0f43));
 #[cfg(target_pointer_width = "64")]
@@ -224,6 +225,7 @@ gen_range_int!(gen_range_i16_high, i16, -500i16, 2000);
 gen_range_int!(gen_range_i32_high, i32, -200_000_000i32, 800_000_000);
 gen_range_int!(gen_range_i64_high, i64, 3i64, 123_456_789_123);
 gen_range_int!(gen_range_i128_high, i128, -12345678901234i128, 123_456_789_123_456_789);
+gen_range_int!(gen_range_u128_narrow, u128, 3u128, 123_456_789_123);
 
 // construct and sample from a floating-point range
 macro_rules! gen_range_float {
@@ -380,6 +380,25 @@ mod test {
         assert_eq!(rng3.next_u32(), decoded3.next_u32());
     }
 
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_chacha_serde_roundtrip_position() {
+        let mut rng = ChaCha20Rng::from_seed([7; 32]);
+        rng.set_stream(505);
+        rng.set_word_pos(1_000_003);
+        rng.next_u32();
+
+        let encoded = serde_json::to_string(&rng).unwrap();
+        let mut decoded: ChaCha20Rng = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.get_stream(), 505);
+        assert_eq!(decoded.get_word_pos(), rng.get_word_pos());
+        let mut buf1 = [0u8; 100];
+        let mut buf2 = [0u8; 100];
+        rng.fill_bytes(&mut buf1);
+        decoded.fill_bytes(&mut buf2);
+        assert_eq!(&buf1[..], &buf2[..]);
+    }
+
     // This test validates that:
     // 1. a hard-coded serialization demonstrating the format at time of initial release can still
     //    be deserialized to a ChaChaRng
@@ -602,6 +621,45 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_chacha_rounds() {
+        use super::{ChaCha8Rng, ChaCha12Rng};
+        let seed = [42; 32];
+        let mut rng8 = ChaCha8Rng::from_seed(seed);
+        let mut rng12 = ChaCha12Rng::from_seed(seed);
+        let mut rng20 = ChaChaRng::from_seed(seed);
+        let mut again8 = ChaCha8Rng::from_seed(seed);
+        let mut again20 = crate::ChaChaRng::from_seed(seed);
+        for _ in 0..16 {
+            let (a, b, c) = (rng8.next_u64(), rng12.next_u64(), rng20.next_u64());
+            assert!(a != b && b != c && a != c);
+            assert_eq!(a, again8.next_u64());
+            // The default `ChaChaRng` uses 20 rounds
+            assert_eq!(c, again20.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_chacha_seek_matches_sequential() {
+        let mut seq = ChaChaRng::from_seed([3; 32]);
+        seq.set_stream(534);
+        let mut words = [0u32; 300];
+        for w in words.iter_mut() {
+            *w = seq.next_u32();
+        }
+
+        let mut rng = ChaChaRng::from_seed([3; 32]);
+        rng.set_stream(534);
+        // Forward, backward and unaligned seeks within and across blocks
+        for &pos in &[299usize, 17, 0, 64, 255, 130] {
+            rng.set_word_pos(pos as u128);
+            assert_eq!(rng.get_word_pos(), pos as u128);
+            for &w in &words[pos..(pos + 10).min(300)] {
+                assert_eq!(rng.next_u32(), w);
+            }
+        }
+    }
+
     #[test]
     fn test_chacha_word_pos_wrap_exact() {
         use super::{BUF_BLOCKS, BLOCK_WORDS};
@@ -181,4 +181,41 @@ mod test {
         assert_eq!(fill_via_u64_chunks(&src, &mut dst), (1, 5));
         assert_eq!(dst, [1, 0, 0, 0, 0]);
     }
+
+    /// An RNG only generating bytes, yielding `0, 1, 2, ...`
+    struct ByteRng(u8);
+
+    impl RngCore for ByteRng {
+        fn next_u32(&mut self) -> u32 {
+            next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for x in dest {
+                *x = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), crate::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_next_via_fill() {
+        let mut rng = ByteRng(0);
+        assert_eq!(rng.next_u32(), 0x0302_0100);
+        assert_eq!(rng.next_u64(), 0x0b0a_0908_0706_0504);
+        assert_eq!(rng.next_u32(), 0x0f0e_0d0c);
+
+        // Composition via `next_u64_via_u32` is consistent
+        let mut rng = ByteRng(0);
+        assert_eq!(next_u64_via_u32(&mut rng), 0x0706_0504_0302_0100);
+    }
 }
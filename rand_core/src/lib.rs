@@ -334,6 +334,32 @@ pub trait SeedableRng: Sized {
         Self::from_seed(seed)
     }
 
+    /// Check whether all bytes of `seed` are zero.
+    ///
+    /// For many generators (e.g. xorshift variants) an all-zero seed yields a
+    /// degenerate state; see also [`SeedableRng::from_nonzero_seed`].
+    fn is_zero_seed(seed: &Self::Seed) -> bool
+    where Self::Seed: AsRef<[u8]> {
+        seed.as_ref().iter().all(|&x| x == 0)
+    }
+
+    /// Create a new PRNG using the given seed, unless all its bytes are zero.
+    ///
+    /// An all-zero seed is deterministically replaced by the repeated
+    /// little-endian bytes of `0x0DDB1A5E5BAD5EEDu64` ("odd biases? bad
+    /// seed"); any other seed is passed to `from_seed` unchanged. This is
+    /// useful for generators for which the zero seed is degenerate but whose
+    /// `from_seed` does not remap it.
+    fn from_nonzero_seed(mut seed: Self::Seed) -> Self {
+        if seed.as_mut().iter().all(|&x| x == 0) {
+            let bytes = 0x0DDB_1A5E_5BAD_5EEDu64.to_le_bytes();
+            for (x, y) in seed.as_mut().iter_mut().zip(bytes.iter().cycle()) {
+                *x = *y;
+            }
+        }
+        Self::from_seed(seed)
+    }
+
     /// Create a new PRNG seeded from another `Rng`.
     ///
     /// This may be useful when needing to rapidly seed many PRNGs from a master
@@ -364,6 +390,17 @@ pub trait SeedableRng: Sized {
         Ok(Self::from_seed(seed))
     }
 
+    /// Reseed this PRNG in place from another `Rng`.
+    ///
+    /// This overwrites the state of `self` with that of a new PRNG seeded
+    /// via [`SeedableRng::from_rng`], thus has the same considerations. This
+    /// may be convenient for long-lived generators which are periodically
+    /// reseeded. On error, `self` is left unchanged.
+    fn reseed_from_rng<R: RngCore>(&mut self, source: &mut R) -> Result<(), Error> {
+        *self = Self::from_rng(source)?;
+        Ok(())
+    }
+
     /// Creates a new instance of the RNG seeded via [`getrandom`].
     ///
     /// This method is the recommended way to construct non-deterministic PRNGs
@@ -499,4 +536,92 @@ mod test {
         // value-breakage test:
         assert_eq!(results[0], 5029875928683246316);
     }
+
+    #[test]
+    fn test_from_nonzero_seed() {
+        // Xorshift is stuck at zero given a zero state
+        #[derive(Debug, PartialEq)]
+        struct XorShift(u64);
+        impl RngCore for XorShift {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impls::fill_bytes_via_next(self, dest)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        impl SeedableRng for XorShift {
+            type Seed = [u8; 8];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut x = [0u64; 1];
+                le::read_u64_into(&seed, &mut x);
+                XorShift(x[0])
+            }
+        }
+
+        assert!(XorShift::is_zero_seed(&[0; 8]));
+        assert!(!XorShift::is_zero_seed(&[0, 0, 0, 1, 0, 0, 0, 0]));
+        assert_eq!(XorShift::from_seed([0; 8]).next_u64(), 0);
+
+        let mut a = XorShift::from_nonzero_seed([0; 8]);
+        assert_eq!(a, XorShift(0x0DDB_1A5E_5BAD_5EED));
+        let mut b = XorShift::from_nonzero_seed([0; 8]);
+        let x = a.next_u64();
+        assert_ne!(x, 0);
+        assert_ne!(a.next_u64(), x);
+        assert_eq!(b.next_u64(), x);
+
+        let seed = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(XorShift::from_nonzero_seed(seed), XorShift::from_seed(seed));
+    }
+
+    #[test]
+    fn test_reseed_from_rng() {
+        #[derive(Debug, PartialEq)]
+        struct Counter(u64);
+        impl RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self.0.wrapping_add(1);
+                self.0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impls::fill_bytes_via_next(self, dest)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        impl SeedableRng for Counter {
+            type Seed = [u8; 8];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut x = [0u64; 1];
+                le::read_u64_into(&seed, &mut x);
+                Counter(x[0])
+            }
+        }
+
+        let mut rng = Counter::from_seed([1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), 2);
+        let mut source = Counter(1000);
+        rng.reseed_from_rng(&mut source).unwrap();
+        assert_eq!(rng, Counter(1001));
+        assert_ne!(rng.next_u64(), 3);
+        assert_eq!(source, Counter(1001));
+    }
 }
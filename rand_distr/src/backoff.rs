@@ -0,0 +1,167 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Exponential backoff with full jitter.
+
+use core::fmt;
+use core::time::Duration;
+use rand::Rng;
+
+/// Exponential backoff delays with full jitter, for retry logic.
+///
+/// The `n`-th call to [`ExponentialBackoff::next_delay`] (counting from 0)
+/// computes the cap `min(max_ms, base_ms * factor^n)` and returns a delay
+/// sampled uniformly from `[0, cap]` milliseconds. The expected delay thus
+/// grows exponentially until the cap is reached, while the jitter spreads out
+/// retries from multiple clients.
+///
+/// Unlike other types in this crate, this type is stateful: each call to
+/// `next_delay` advances the attempt count. Use [`ExponentialBackoff::reset`]
+/// to start over.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::ExponentialBackoff;
+///
+/// let mut backoff = ExponentialBackoff::new(10, 1000, 2.0).unwrap();
+/// for _ in 0..5 {
+///     let delay = backoff.next_delay(&mut rand::thread_rng());
+///     assert!(delay.as_millis() <= 1000);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExponentialBackoff {
+    base_ms: u64,
+    max_ms: u64,
+    factor: f64,
+    attempt: u32,
+    current_ms: f64,
+}
+
+/// Error type returned from `ExponentialBackoff::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `base_ms > max_ms`.
+    BaseTooLarge,
+    /// `factor < 1` or `nan`.
+    FactorTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BaseTooLarge => "base_ms > max_ms in exponential backoff",
+            Error::FactorTooSmall => "factor < 1 or is NaN in exponential backoff",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl ExponentialBackoff {
+    /// Construct a new `ExponentialBackoff` with the initial delay cap
+    /// `base_ms`, the maximal delay cap `max_ms` and growth rate `factor`.
+    pub fn new(base_ms: u64, max_ms: u64, factor: f64) -> Result<ExponentialBackoff, Error> {
+        if base_ms > max_ms {
+            return Err(Error::BaseTooLarge);
+        }
+        if !(factor >= 1.0) {
+            return Err(Error::FactorTooSmall);
+        }
+        Ok(ExponentialBackoff {
+            base_ms,
+            max_ms,
+            factor,
+            attempt: 0,
+            current_ms: base_ms as f64,
+        })
+    }
+
+    /// The number of delays returned since construction or the last reset.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Reset the attempt count, such that the next delay is again capped by
+    /// `base_ms`.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+        self.current_ms = self.base_ms as f64;
+    }
+
+    /// Sample the next delay and advance the attempt count.
+    pub fn next_delay<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Duration {
+        let cap = if self.current_ms >= self.max_ms as f64 {
+            self.max_ms
+        } else {
+            self.current_ms as u64
+        };
+        // Stop growing once the cap is reached; this also avoids overflow
+        // to infinity for long retry sequences.
+        if self.current_ms < self.max_ms as f64 {
+            self.current_ms *= self.factor;
+        }
+        self.attempt = self.attempt.saturating_add(1);
+        Duration::from_millis(rng.gen_range(0..=cap))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backoff_invalid() {
+        assert_eq!(ExponentialBackoff::new(11, 10, 2.0), Err(Error::BaseTooLarge));
+        assert_eq!(ExponentialBackoff::new(1, 10, 0.5), Err(Error::FactorTooSmall));
+        assert_eq!(ExponentialBackoff::new(1, 10, core::f64::NAN), Err(Error::FactorTooSmall));
+    }
+
+    #[test]
+    fn test_backoff_cap() {
+        let mut rng = crate::test::rng(482);
+        let mut backoff = ExponentialBackoff::new(3, 500, 1.5).unwrap();
+        for i in 0..100 {
+            assert_eq!(backoff.attempt(), i);
+            assert!(backoff.next_delay(&mut rng) <= Duration::from_millis(500));
+        }
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 0);
+        assert!(backoff.next_delay(&mut rng) <= Duration::from_millis(3));
+
+        let mut backoff = ExponentialBackoff::new(0, 0, 2.0).unwrap();
+        assert_eq!(backoff.next_delay(&mut rng), Duration::from_millis(0));
+    }
+
+    #[test]
+    fn test_backoff_mean() {
+        let mut rng = crate::test::rng(483);
+        const N: u64 = 2000;
+        const ATTEMPTS: usize = 10;
+        let mut sums = [0u64; ATTEMPTS];
+        for _ in 0..N {
+            let mut backoff = ExponentialBackoff::new(10, 1000, 2.0).unwrap();
+            for sum in sums.iter_mut() {
+                *sum += backoff.next_delay(&mut rng).as_millis() as u64;
+            }
+        }
+        // Expected means are cap / 2, with caps 10, 20, ..., 640, 1000, ...
+        let mut cap = 10.0f64;
+        for &sum in sums.iter() {
+            let mean = sum as f64 / N as f64;
+            assert_almost_eq!(mean, cap.min(1000.0) / 2.0, 0.1 * cap.min(1000.0));
+            cap *= 2.0;
+        }
+        for w in sums.windows(2).take(6) {
+            assert!(w[0] < w[1]);
+        }
+    }
+}
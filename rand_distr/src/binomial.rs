@@ -340,6 +340,31 @@ mod test {
         assert_eq!(rng.sample(Binomial::new(20, 1.0).unwrap()), 20);
     }
 
+    fn test_binomial_pmf<R: Rng>(n: u64, p: f64, rng: &mut R) {
+        let binomial = Binomial::new(n, p).unwrap();
+        const N: usize = 100_000;
+        let mut counts = [0usize; 101];
+        for _ in 0..N {
+            counts[binomial.sample(rng) as usize] += 1;
+        }
+
+        // P(X = k) via the recurrence P(k + 1) = P(k) (n - k) p / ((k + 1) (1 - p))
+        let mut pmf = (1.0 - p).powi(n as i32);
+        for (k, &count) in counts.iter().enumerate().take(n as usize + 1) {
+            let freq = count as f64 / N as f64;
+            assert!((freq - pmf).abs() < 0.006, "k = {}: {} vs {}", k, freq, pmf);
+            pmf *= (n - k as u64) as f64 * p / ((k + 1) as f64 * (1.0 - p));
+        }
+    }
+
+    #[test]
+    fn test_binomial_distribution() {
+        let mut rng = crate::test::rng(353);
+        // Small `n * p` uses the inversion algorithm, large uses BTPE
+        test_binomial_pmf(20, 0.3, &mut rng);
+        test_binomial_pmf(100, 0.3, &mut rng);
+    }
+
     #[test]
     #[should_panic]
     fn test_binomial_invalid_lambda_neg() {
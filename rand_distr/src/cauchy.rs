@@ -122,6 +122,24 @@ mod test {
         assert!((mean - 10.0).abs() > 0.4); // not 100% certain, but probable enough
     }
 
+    #[test]
+    fn test_cauchy_quartiles() {
+        // The quartiles are median ± scale, thus the interquartile range is
+        // twice the scale.
+        let mut rng = crate::test::rng(124);
+        for &scale in &[0.5, 5.0] {
+            let cauchy = Cauchy::new(-3.0, scale).unwrap();
+            let mut numbers: [f64; 4000] = [0.0; 4000];
+            for number in &mut numbers[..] {
+                *number = cauchy.sample(&mut rng);
+            }
+            sort(&mut numbers);
+            let (q1, q2, q3) = (numbers[1000], numbers[2000], numbers[3000]);
+            assert_almost_eq!(q2, -3.0, 0.1 * scale);
+            assert_almost_eq!(q3 - q1, 2.0 * scale, 0.2 * scale);
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_cauchy_invalid_scale_zero() {
@@ -0,0 +1,134 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The chi distribution.
+
+use num_traits::Float;
+use crate::{ChiSquared, Distribution, Exp1, Open01, StandardNormal};
+use rand::Rng;
+use core::fmt;
+
+/// The chi distribution `χ(k)`, where `k` is the degrees of freedom.
+///
+/// This is the distribution of the square root of a chi-squared variable
+/// with `k` degrees of freedom, i.e. of the Euclidean norm of a vector of `k`
+/// independent standard normal samples. With `k = 2` this is the Rayleigh
+/// distribution with scale 1, and with `k = 3` the [`Maxwell`] distribution
+/// with scale 1.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Chi, Distribution};
+///
+/// let chi = Chi::new(4.0).unwrap();
+/// let v = chi.sample(&mut rand::thread_rng());
+/// println!("{} is from a χ(4) distribution", v);
+/// ```
+///
+/// [`Maxwell`]: crate::Maxwell
+#[derive(Clone, Copy, Debug)]
+pub struct Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    chi_squared: ChiSquared<F>,
+}
+
+/// Error type returned from `Chi::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `k <= 0` or `nan`.
+    DoFTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::DoFTooSmall => "degrees-of-freedom k is not positive in chi distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new chi distribution with `k` degrees of freedom.
+    pub fn new(k: F) -> Result<Chi<F>, Error> {
+        let chi_squared = ChiSquared::new(k).map_err(|_| Error::DoFTooSmall)?;
+        Ok(Chi { chi_squared })
+    }
+}
+
+impl<F> Distribution<F> for Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.chi_squared.sample(rng).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Chi::new(0.).unwrap_err(), Error::DoFTooSmall);
+        assert_eq!(Chi::new(-1.).unwrap_err(), Error::DoFTooSmall);
+        assert_eq!(Chi::new(core::f64::NAN).unwrap_err(), Error::DoFTooSmall);
+    }
+
+    #[test]
+    fn rayleigh() {
+        // χ(2) is the Rayleigh distribution with CDF 1 - exp(-x²/2)
+        let d = Chi::new(2.).unwrap();
+        let mut rng = crate::test::rng(537);
+        const N: usize = 20_000;
+        let mut samples = [0f64; N];
+        for x in samples.iter_mut() {
+            *x = d.sample(&mut rng);
+            assert!(*x >= 0.);
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for &p in &[0.1, 0.5, 0.9] {
+            let q = (-2. * (1f64 - p).ln()).sqrt();
+            assert_almost_eq!(samples[(p * N as f64) as usize], q, 0.03);
+        }
+    }
+
+    #[test]
+    fn maxwell() {
+        // χ(3) has the mean 2 √(2/π) of a Maxwell distribution with scale 1
+        let d = Chi::new(3.).unwrap();
+        let mut rng = crate::test::rng(538);
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            sum += x;
+        }
+        let mean = 2. * (2. / core::f64::consts::PI).sqrt();
+        assert_almost_eq!(sum / N as f64, mean, 0.02);
+    }
+}
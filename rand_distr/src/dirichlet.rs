@@ -171,6 +171,29 @@ mod test {
             .collect();
     }
 
+    #[test]
+    fn test_dirichlet_sum_and_mean() {
+        let alpha = [0.5, 1.0, 2.0, 4.5];
+        let d = Dirichlet::new(&alpha).unwrap();
+        let mut rng = crate::test::rng(514);
+        const N: usize = 10_000;
+        let mut means = [0.0; 4];
+        for _ in 0..N {
+            let samples = d.sample(&mut rng);
+            assert_eq!(samples.len(), alpha.len());
+            assert_almost_eq!(samples.iter().sum::<f64>(), 1.0, 1e-12);
+            for (mean, x) in means.iter_mut().zip(samples) {
+                *mean += x / N as f64;
+            }
+        }
+        // The mean of component i is alpha_i / sum(alpha)
+        for (&mean, &a) in means.iter().zip(alpha.iter()) {
+            assert_almost_eq!(mean, a / 8.0, 0.01);
+        }
+        assert!(Dirichlet::<f64>::new(&[]).is_err());
+        assert!(Dirichlet::new(&[1.0, -1.0]).is_err());
+    }
+
     #[test]
     #[should_panic]
     fn test_dirichlet_invalid_length() {
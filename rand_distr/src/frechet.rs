@@ -0,0 +1,136 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Fréchet distribution.
+
+use num_traits::Float;
+use crate::{Distribution, Open01};
+use rand::Rng;
+use core::fmt;
+
+/// Samples floating-point numbers according to the Fréchet distribution
+///
+/// This is the type-II extreme value distribution, with density
+/// `f(x) = (α/s) ((x-m)/s)^(-1-α) exp(-((x-m)/s)^(-α))` for `x > m`, where
+/// `m` is the location, `s > 0` the scale and `α > 0` the shape. It is
+/// heavy-tailed: moments of order `α` and above are infinite.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::Frechet;
+///
+/// let val: f64 = thread_rng().sample(Frechet::new(0., 1., 2.).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Frechet<F>
+where F: Float, Open01: Distribution<F>
+{
+    location: F,
+    scale: F,
+    neg_inv_shape: F,
+}
+
+/// Error type returned from `Frechet::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// location is infinite or `nan`.
+    LocationNotFinite,
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+    /// `shape <= 0` or `nan`.
+    ShapeTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LocationNotFinite => "location is not finite in Fréchet distribution",
+            Error::ScaleTooSmall => "scale is not positive in Fréchet distribution",
+            Error::ShapeTooSmall => "shape is not positive in Fréchet distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Frechet<F>
+where F: Float, Open01: Distribution<F>
+{
+    /// Construct a new `Frechet` distribution with given `location`, `scale`
+    /// and `shape`.
+    pub fn new(location: F, scale: F, shape: F) -> Result<Frechet<F>, Error> {
+        if !location.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        if !(shape > F::zero()) {
+            return Err(Error::ShapeTooSmall);
+        }
+        Ok(Frechet {
+            location,
+            scale,
+            neg_inv_shape: -F::one() / shape,
+        })
+    }
+}
+
+impl<F> Distribution<F> for Frechet<F>
+where F: Float, Open01: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let x: F = rng.sample(Open01);
+        self.location + self.scale * (-x.ln()).powf(self.neg_inv_shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Frechet::new(core::f64::NAN, 1., 1.).unwrap_err(), Error::LocationNotFinite);
+        assert_eq!(Frechet::new(0., 0., 1.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(Frechet::new(0., 1., -1.).unwrap_err(), Error::ShapeTooSmall);
+        assert_eq!(Frechet::new(0., 1., core::f64::NAN).unwrap_err(), Error::ShapeTooSmall);
+    }
+
+    #[test]
+    fn sample() {
+        let location = 2.0;
+        let d = Frechet::new(location, 1.5, 1.0).unwrap();
+        let mut rng = crate::test::rng(500);
+        let mut large = 0;
+        for _ in 0..10_000 {
+            let x: f64 = d.sample(&mut rng);
+            assert!(x > location);
+            if x > location + 1.5 * 100. {
+                large += 1;
+            }
+        }
+        // P(X > m + 100s) = 1 - exp(-1/100) ≈ 1%, a heavy tail
+        assert!(large > 50 && large < 150);
+    }
+
+    #[test]
+    fn value_stability() {
+        let d = Frechet::new(0., 1., 2.).unwrap();
+        let mut rng = crate::test::rng(501);
+        let mut rng2 = crate::test::rng(501);
+        for _ in 0..10 {
+            let u: f64 = rng2.sample(Open01);
+            assert_eq!(d.sample(&mut rng), (-u.ln()).powf(-0.5));
+        }
+    }
+}
@@ -748,6 +748,25 @@ mod test {
         }
     }
     #[test]
+    fn test_chi_squared_moments() {
+        let mut rng = crate::test::rng(208);
+        // k = 1 takes a special path; other values delegate to Gamma
+        for &k in &[1.0, 2.5, 5.0] {
+            let chi = ChiSquared::new(k).unwrap();
+            const N: usize = 20_000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..N {
+                let x: f64 = chi.sample(&mut rng);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / N as f64;
+            assert_almost_eq!(mean, k, 0.05 * k);
+            assert_almost_eq!(sum_sq / N as f64 - mean * mean, 2.0 * k, 0.1 * 2.0 * k);
+        }
+    }
+    #[test]
     #[should_panic]
     fn test_chi_squared_invalid_dof() {
         ChiSquared::new(-1.0).unwrap();
@@ -794,4 +813,20 @@ mod test {
             assert!(!beta.sample(&mut rng).is_nan(), "failed at i={}", i);
         }
     }
+
+    #[test]
+    fn test_beta_mean_and_bounds() {
+        let mut rng = crate::test::rng(207);
+        for &(a, b) in &[(0.1, 0.1), (0.5, 2.0), (2.0, 5.0), (30.0, 4.0)] {
+            let beta = Beta::new(a, b).unwrap();
+            const N: usize = 20_000;
+            let mut sum = 0.0;
+            for _ in 0..N {
+                let x: f64 = beta.sample(&mut rng);
+                assert!((0.0..=1.0).contains(&x), "Beta({}, {}) sampled {}", a, b, x);
+                sum += x;
+            }
+            assert_almost_eq!(sum / N as f64, a / (a + b), 0.01);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generalized Pareto distribution.
+
+use num_traits::Float;
+use crate::{Distribution, OpenClosed01};
+use rand::Rng;
+use core::fmt;
+
+/// Samples floating-point numbers according to the generalized Pareto
+/// distribution
+///
+/// With location `m`, scale `s > 0` and shape `ξ`, the CDF is
+/// `F(x) = 1 - (1 + ξ (x-m)/s)^(-1/ξ)`, or in the limit `ξ = 0`,
+/// `F(x) = 1 - exp(-(x-m)/s)`, a shifted exponential distribution. The
+/// support is `x >= m` for `ξ >= 0` and `m <= x <= m - s/ξ` for `ξ < 0`.
+///
+/// This is the limiting distribution of exceedances over a high threshold,
+/// as used in peaks-over-threshold modelling. Sampling uses the inverse CDF.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::GeneralizedPareto;
+///
+/// let val: f64 = thread_rng().sample(GeneralizedPareto::new(0., 1., 0.5).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GeneralizedPareto<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    location: F,
+    scale: F,
+    shape: F,
+}
+
+/// Error type returned from `GeneralizedPareto::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// location is infinite or `nan`.
+    LocationNotFinite,
+    /// `scale <= 0`, infinite or `nan`.
+    ScaleTooSmall,
+    /// shape is infinite or `nan`.
+    ShapeNotFinite,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LocationNotFinite => "location is not finite in generalized Pareto distribution",
+            Error::ScaleTooSmall => "scale is not positive and finite in generalized Pareto distribution",
+            Error::ShapeNotFinite => "shape is not finite in generalized Pareto distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> GeneralizedPareto<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    /// Construct a new `GeneralizedPareto` distribution with given
+    /// `location`, `scale` and `shape`.
+    pub fn new(location: F, scale: F, shape: F) -> Result<GeneralizedPareto<F>, Error> {
+        if !location.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(Error::ScaleTooSmall);
+        }
+        if !shape.is_finite() {
+            return Err(Error::ShapeNotFinite);
+        }
+        Ok(GeneralizedPareto { location, scale, shape })
+    }
+}
+
+impl<F> Distribution<F> for GeneralizedPareto<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // 1 - F(X) is uniform; u = 1 yields the location.
+        let u: F = rng.sample(OpenClosed01);
+        let y = if self.shape == F::zero() {
+            -u.ln()
+        } else {
+            (u.powf(-self.shape) - F::one()) / self.shape
+        };
+        self.location + self.scale * y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            GeneralizedPareto::new(core::f64::INFINITY, 1., 1.).unwrap_err(),
+            Error::LocationNotFinite
+        );
+        assert_eq!(GeneralizedPareto::new(0., 0., 1.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(
+            GeneralizedPareto::new(0., core::f64::NAN, 1.).unwrap_err(),
+            Error::ScaleTooSmall
+        );
+        assert_eq!(
+            GeneralizedPareto::new(0., 1., core::f64::NAN).unwrap_err(),
+            Error::ShapeNotFinite
+        );
+    }
+
+    #[test]
+    fn sample_exponential_limit() {
+        // shape = 0 is a shifted exponential distribution with mean m + s
+        let d = GeneralizedPareto::new(1., 2., 0.).unwrap();
+        let mut rng = crate::test::rng(509);
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!(x >= 1.);
+            sum += x;
+        }
+        assert_almost_eq!(sum / N as f64, 3., 0.1);
+    }
+
+    #[test]
+    fn sample_support() {
+        let mut rng = crate::test::rng(510);
+        // shape > 0: unbounded above, mean m + s / (1 - ξ) for ξ < 1
+        let d = GeneralizedPareto::new(-1., 1., 0.25).unwrap();
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!(x >= -1.);
+            sum += x;
+        }
+        assert_almost_eq!(sum / N as f64, -1. + 1. / 0.75, 0.1);
+
+        // shape < 0: bounded by m - s/ξ; ξ = -1 is uniform on [m, m + s]
+        let d = GeneralizedPareto::new(2., 3., -1.).unwrap();
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!((2. ..=5.).contains(&x));
+            sum += x;
+        }
+        assert_almost_eq!(sum / N as f64, 3.5, 0.05);
+    }
+}
@@ -211,6 +211,21 @@ mod test {
         test_geo_mean_and_variance(0.90, &mut rng);
     }
 
+    #[test]
+    fn test_geometric_trials() {
+        // Samples count failures; the number of trials is one more.
+        let mut rng = crate::test::rng(511);
+        let certain = Geometric::new(1.0).unwrap();
+        for _ in 0..100 {
+            assert_eq!(certain.sample(&mut rng) + 1, 1);
+        }
+
+        let distr = Geometric::new(0.2).unwrap();
+        const N: u64 = 10_000;
+        let trials: u64 = (0..N).map(|_| distr.sample(&mut rng) + 1).sum();
+        assert_almost_eq!(trials as f64 / N as f64, 5.0, 0.15);
+    }
+
     #[test]
     fn test_standard_geometric() {
         let mut rng = crate::test::rng(654321);
@@ -0,0 +1,131 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Gompertz distribution.
+
+use num_traits::Float;
+use crate::{Distribution, OpenClosed01};
+use rand::Rng;
+use core::fmt;
+
+/// Samples floating-point numbers according to the Gompertz distribution
+///
+/// This distribution has the exponentially increasing hazard function
+/// `h(x) = a exp(b x)` for `x >= 0`, where `a > 0` is the `shape` (the hazard
+/// at `x = 0`) and `b > 0` the `scale` (the growth rate of the hazard). The
+/// CDF is `F(x) = 1 - exp(-(a/b) (exp(b x) - 1))`. It is a classic model of
+/// adult mortality, and sampling uses the inverse CDF.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::Gompertz;
+///
+/// let val: f64 = thread_rng().sample(Gompertz::new(1e-4, 0.085).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Gompertz<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    inv_scale: F,
+    scale_over_shape: F,
+}
+
+/// Error type returned from `Gompertz::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `shape <= 0` or `nan`.
+    ShapeTooSmall,
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ShapeTooSmall => "shape is not positive in Gompertz distribution",
+            Error::ScaleTooSmall => "scale is not positive in Gompertz distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Gompertz<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    /// Construct a new `Gompertz` distribution with given `shape` and `scale`.
+    pub fn new(shape: F, scale: F) -> Result<Gompertz<F>, Error> {
+        if !(shape > F::zero()) {
+            return Err(Error::ShapeTooSmall);
+        }
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        Ok(Gompertz {
+            inv_scale: F::one() / scale,
+            scale_over_shape: scale / shape,
+        })
+    }
+}
+
+impl<F> Distribution<F> for Gompertz<F>
+where F: Float, OpenClosed01: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // u takes the place of 1 - U in the inverse CDF
+        let u: F = rng.sample(OpenClosed01);
+        self.inv_scale * (-self.scale_over_shape * u.ln()).ln_1p()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Gompertz::new(0., 1.).unwrap_err(), Error::ShapeTooSmall);
+        assert_eq!(Gompertz::new(core::f64::NAN, 1.).unwrap_err(), Error::ShapeTooSmall);
+        assert_eq!(Gompertz::new(1., -1.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(Gompertz::new(1., core::f64::NAN).unwrap_err(), Error::ScaleTooSmall);
+    }
+
+    #[test]
+    fn sample() {
+        let (shape, scale) = (0.1, 1.0);
+        let d = Gompertz::new(shape, scale).unwrap();
+        let mut rng = crate::test::rng(511);
+        const N: usize = 20_000;
+        let mut samples = [0f64; N];
+        for x in samples.iter_mut() {
+            *x = d.sample(&mut rng);
+            assert!(*x >= 0.);
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // Quantiles match the inverse CDF
+        for &p in &[0.1, 0.5, 0.9] {
+            let q = (1. - scale / shape * (1f64 - p).ln()).ln() / scale;
+            assert_almost_eq!(samples[(p * N as f64) as usize], q, 0.05);
+        }
+
+        // The hazard increases with age: among survivors to age t, the
+        // fraction dying within the next 0.5 grows with t.
+        let survivors = |t: f64| samples.iter().filter(|&&x| x >= t).count() as f64;
+        let mut last = 0.;
+        for &t in &[0., 0.5, 1., 1.5, 2.] {
+            let hazard = 1. - survivors(t + 0.5) / survivors(t);
+            assert!(hazard > last);
+            last = hazard;
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Inverse transform sampling.
+
+use num_traits::Float;
+use crate::{Distribution, Open01};
+use rand::Rng;
+use core::marker::PhantomData;
+
+/// Samples via a user-supplied inverse cumulative distribution function.
+///
+/// Given the inverse `f` of the CDF of a distribution on the reals, sampling
+/// `u` uniformly from the open interval `(0, 1)` and returning `f(u)` yields
+/// samples from that distribution. This is the most general (though often
+/// not the fastest) method of sampling a univariate distribution.
+///
+/// No checks are applied to `f`; it should be (weakly) increasing on `(0, 1)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{InverseCdf, Distribution};
+///
+/// // Exponential distribution with rate 2
+/// let exp = InverseCdf::new(|u: f64| -(1.0 - u).ln() / 2.0);
+/// let v = exp.sample(&mut rand::thread_rng());
+/// println!("{} is from an Exp(2) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct InverseCdf<F, C> {
+    inv_cdf: C,
+    phantom: PhantomData<fn(F) -> F>,
+}
+
+impl<F, C> InverseCdf<F, C>
+where F: Float, Open01: Distribution<F>, C: Fn(F) -> F
+{
+    /// Construct a new `InverseCdf` sampler from the inverse CDF `inv_cdf`.
+    pub fn new(inv_cdf: C) -> InverseCdf<F, C> {
+        InverseCdf { inv_cdf, phantom: PhantomData }
+    }
+}
+
+impl<F, C> Distribution<F> for InverseCdf<F, C>
+where F: Float, Open01: Distribution<F>, C: Fn(F) -> F
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let u: F = rng.sample(Open01);
+        (self.inv_cdf)(u)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_inverse_cdf_exp() {
+        let mut rng = crate::test::rng(495);
+        let lambda = 2.0;
+        let d = InverseCdf::new(|u: f64| -(1.0 - u).ln() / lambda);
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x = d.sample(&mut rng);
+            assert!(x >= 0. && x.is_finite());
+            sum += x;
+        }
+        assert_almost_eq!(sum / N as f64, 1. / lambda, 0.02);
+    }
+
+    #[test]
+    fn test_inverse_cdf_f32() {
+        let mut rng = crate::test::rng(496);
+        let d = InverseCdf::new(|u: f32| 3. * u - 1.);
+        for _ in 0..100 {
+            let x = d.sample(&mut rng);
+            assert!(x > -1. && x < 2.);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Irwin–Hall distribution.
+
+use num_traits::Float;
+use crate::{Distribution, Standard};
+use rand::Rng;
+use core::fmt;
+
+/// The Irwin–Hall distribution `IrwinHall(n)`.
+///
+/// This is the distribution of the sum of `n` independent samples from the
+/// uniform distribution on `[0, 1)`. It has mean `n / 2` and variance
+/// `n / 12`, and approaches a normal distribution as `n` grows.
+///
+/// Sampling requires `n` uniform samples, thus this is only a cheap
+/// approximation of a normal distribution for small `n`; see
+/// [`IrwinHall::bates_normal`].
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{IrwinHall, Distribution};
+///
+/// let ih = IrwinHall::new(4).unwrap();
+/// let v: f64 = ih.sample(&mut rand::thread_rng());
+/// println!("{} is from an IrwinHall(4) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IrwinHall<F>
+where F: Float, Standard: Distribution<F>
+{
+    n: u32,
+    phantom: core::marker::PhantomData<F>,
+}
+
+/// Error type returned from `IrwinHall::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `n == 0`.
+    NTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NTooSmall => "n is zero in Irwin-Hall distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> IrwinHall<F>
+where F: Float, Standard: Distribution<F>
+{
+    /// Construct a new `IrwinHall` distribution summing `n` uniform samples.
+    pub fn new(n: u32) -> Result<IrwinHall<F>, Error> {
+        if n == 0 {
+            return Err(Error::NTooSmall);
+        }
+        Ok(IrwinHall { n, phantom: core::marker::PhantomData })
+    }
+
+    /// Sample an approximately standard normal value as `IrwinHall(12) - 6`.
+    ///
+    /// The result has mean 0 and variance 1, but is bounded to `[-6, 6)`
+    /// and thus has far lighter tails than [`StandardNormal`]. This is faster
+    /// than exact methods only where uniform samples are very cheap.
+    ///
+    /// [`StandardNormal`]: crate::StandardNormal
+    pub fn bates_normal<R: Rng + ?Sized>(rng: &mut R) -> F {
+        IrwinHall { n: 12, phantom: core::marker::PhantomData }.sample(rng)
+            - F::from(6.).unwrap()
+    }
+}
+
+impl<F> Distribution<F> for IrwinHall<F>
+where F: Float, Standard: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let mut sum = F::zero();
+        for _ in 0..self.n {
+            let x: F = rng.sample(Standard);
+            sum = sum + x;
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_irwin_hall_invalid_n() {
+        IrwinHall::<f64>::new(0).unwrap();
+    }
+
+    #[test]
+    fn test_irwin_hall_moments() {
+        let mut rng = crate::test::rng(476);
+        for &n in &[1u32, 3, 12] {
+            let d = IrwinHall::new(n).unwrap();
+            const N: usize = 20_000;
+            let mut samples = [0f64; N];
+            for x in samples.iter_mut() {
+                *x = d.sample(&mut rng);
+                assert!(*x >= 0. && *x < n as f64);
+            }
+            let mean = samples.iter().sum::<f64>() / N as f64;
+            let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>()
+                / N as f64;
+            assert_almost_eq!(mean, n as f64 / 2., 0.05);
+            assert_almost_eq!(var, n as f64 / 12., 0.05);
+        }
+    }
+
+    #[test]
+    fn test_bates_normal() {
+        let mut rng = crate::test::rng(477);
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        let mut sum_sq = 0.;
+        for _ in 0..N {
+            let x: f64 = IrwinHall::bates_normal(&mut rng);
+            assert!((-6. ..6.).contains(&x));
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / N as f64;
+        assert_almost_eq!(mean, 0., 0.05);
+        assert_almost_eq!(sum_sq / N as f64 - mean * mean, 1., 0.05);
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Lévy distribution.
+
+use num_traits::Float;
+use crate::{Distribution, StandardNormal};
+use rand::Rng;
+use core::fmt;
+
+/// Samples floating-point numbers according to the Lévy distribution
+///
+/// With location `μ` and scale `c > 0`, the density is
+/// `f(x) = sqrt(c / 2π) exp(-c / (2(x-μ))) / (x-μ)^1.5` for `x > μ`. This
+/// is the distribution of the first passage time of a Brownian motion, a
+/// stable distribution whose tail is so heavy that the mean is infinite.
+///
+/// Sampling uses `μ + c / Z^2`, where `Z` is standard normal.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::Levy;
+///
+/// let val: f64 = thread_rng().sample(Levy::new(0., 1.).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Levy<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    location: F,
+    scale: F,
+}
+
+/// Error type returned from `Levy::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ScaleTooSmall => "scale is not positive in Lévy distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Levy<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    /// Construct a new `Levy` distribution with given `location` and `scale`.
+    pub fn new(location: F, scale: F) -> Result<Levy<F>, Error> {
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        Ok(Levy { location, scale })
+    }
+}
+
+impl<F> Distribution<F> for Levy<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let z: F = rng.sample(StandardNormal);
+        self.location + self.scale / (z * z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Levy::new(0., 0.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(Levy::new(0., core::f64::NAN).unwrap_err(), Error::ScaleTooSmall);
+    }
+
+    #[test]
+    fn sample() {
+        let d = Levy::new(2., 0.5).unwrap();
+        let mut rng = crate::test::rng(519);
+        const N: usize = 20_000;
+        let mut samples = [0f64; N];
+        for x in samples.iter_mut() {
+            *x = d.sample(&mut rng);
+            assert!(*x > 2.);
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // The median is μ + c / (2 erfc^-1(1/2)^2) ≈ μ + 2.198 c
+        assert_almost_eq!(samples[N / 2], 2. + 2.198 * 0.5, 0.05);
+
+        // Heavy tail: P(X - μ > 1000 c) ≈ 2.5%
+        let large = samples.iter().filter(|&&x| x > 2. + 500.).count();
+        assert!(large > N / 100 && large < N / 20);
+    }
+}
@@ -44,6 +44,8 @@
 //!   (e.g. errors, offsets):
 //!   - [`Normal`] distribution, and [`StandardNormal`] as a primitive
 //!   - [`Cauchy`] distribution
+//!   - [`FoldedNormal`] distribution
+//!   - [`GaussianMixture2`] mixture of two normal distributions
 //! - Related to Bernoulli trials (yes/no events, with a given probability):
 //!   - [`Binomial`] distribution
 //!   - [`Geometric`] distribution
@@ -53,12 +55,16 @@
 //!   - [`LogNormal`] distribution
 //! - Related to the occurrence of independent events at a given rate:
 //!   - [`Pareto`] distribution
+//!   - [`GeneralizedPareto`] distribution
 //!   - [`Poisson`] distribution
 //!   - [`Exp`]onential distribution, and [`Exp1`] as a primitive
 //!   - [`Weibull`] distribution
+//!   - [`Frechet`] distribution
+//!   - [`Gompertz`] distribution
 //! - Gamma and derived distributions:
 //!   - [`Gamma`] distribution
 //!   - [`ChiSquared`] distribution
+//!   - [`Chi`] distribution
 //!   - [`StudentT`] distribution
 //!   - [`FisherF`] distribution
 //! - Triangular distribution:
@@ -73,8 +79,15 @@
 //! - Alternative implementation for weighted index sampling
 //!   - [`WeightedAliasIndex`] distribution
 //! - Misc. distributions
+//!   - [`InverseCdf`] sampling from a user-supplied inverse CDF
 //!   - [`InverseGaussian`] distribution
+//!   - [`Levy`] distribution
 //!   - [`NormalInverseGaussian`] distribution
+//!   - [`IrwinHall`] distribution
+//!   - [`Wigner`] semicircle distribution
+//!   - [`Maxwell`]–Boltzmann distribution
+//! - Retry delays
+//!   - [`ExponentialBackoff`] with full jitter
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -91,20 +104,32 @@ pub use rand::distributions::{
     Standard, Uniform,
 };
 
+pub use self::backoff::{Error as ExponentialBackoffError, ExponentialBackoff};
 pub use self::binomial::{Binomial, Error as BinomialError};
 pub use self::cauchy::{Cauchy, Error as CauchyError};
+pub use self::chi::{Chi, Error as ChiError};
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub use self::dirichlet::{Dirichlet, Error as DirichletError};
 pub use self::exponential::{Error as ExpError, Exp, Exp1};
+pub use self::frechet::{Error as FrechetError, Frechet};
 pub use self::gamma::{
     Beta, BetaError, ChiSquared, ChiSquaredError, Error as GammaError, FisherF, FisherFError,
     Gamma, StudentT,
 };
+pub use self::generalized_pareto::{Error as GeneralizedParetoError, GeneralizedPareto};
 pub use self::geometric::{Error as GeoError, Geometric, StandardGeometric};
+pub use self::gompertz::{Error as GompertzError, Gompertz};
 pub use self::hypergeometric::{Error as HyperGeoError, Hypergeometric};
+pub use self::inverse_cdf::InverseCdf;
 pub use self::inverse_gaussian::{InverseGaussian, Error as InverseGaussianError};
-pub use self::normal::{Error as NormalError, LogNormal, Normal, StandardNormal};
+pub use self::irwin_hall::{Error as IrwinHallError, IrwinHall};
+pub use self::levy::{Error as LevyError, Levy};
+pub use self::maxwell::{Error as MaxwellError, Maxwell};
+pub use self::normal::{
+    Error as NormalError, FoldedNormal, GaussianMixture2, GaussianMixture2Error, LogNormal, Normal,
+    StandardNormal,
+};
 pub use self::normal_inverse_gaussian::{NormalInverseGaussian, Error as NormalInverseGaussianError};
 pub use self::pareto::{Error as ParetoError, Pareto};
 pub use self::pert::{Pert, PertError};
@@ -115,6 +140,7 @@ pub use self::unit_circle::UnitCircle;
 pub use self::unit_disc::UnitDisc;
 pub use self::unit_sphere::UnitSphere;
 pub use self::weibull::{Error as WeibullError, Weibull};
+pub use self::wigner::{Error as WignerError, Wigner};
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub use rand::distributions::{WeightedError, WeightedIndex};
@@ -177,14 +203,23 @@ mod test {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod weighted_alias;
 
+mod backoff;
 mod binomial;
 mod cauchy;
+mod chi;
 mod dirichlet;
 mod exponential;
+mod frechet;
 mod gamma;
+mod generalized_pareto;
 mod geometric;
+mod gompertz;
 mod hypergeometric;
+mod inverse_cdf;
 mod inverse_gaussian;
+mod irwin_hall;
+mod levy;
+mod maxwell;
 mod normal;
 mod normal_inverse_gaussian;
 mod pareto;
@@ -197,5 +232,6 @@ mod unit_disc;
 mod unit_sphere;
 mod utils;
 mod weibull;
+mod wigner;
 mod ziggurat_tables;
 
@@ -0,0 +1,109 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Maxwell–Boltzmann distribution.
+
+use num_traits::Float;
+use crate::{Distribution, StandardNormal};
+use rand::Rng;
+use core::fmt;
+
+/// The Maxwell–Boltzmann distribution `Maxwell(a)`.
+///
+/// This is the distribution of the magnitude of a 3-dimensional vector
+/// whose components are independent `N(0, a²)` samples, i.e. `a` times the
+/// square root of a chi-squared variable with 3 degrees of freedom. In
+/// physics, it models the speeds of particles in an ideal gas.
+///
+/// The mean is `2a √(2/π)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Maxwell, Distribution};
+///
+/// let m = Maxwell::new(2.0).unwrap();
+/// let v = m.sample(&mut rand::thread_rng());
+/// println!("{} is from a Maxwell(2) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Maxwell<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    scale: F,
+}
+
+/// Error type returned from `Maxwell::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ScaleTooSmall => "scale is not positive in Maxwell distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Maxwell<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    /// Construct a new `Maxwell` distribution with the given `scale`.
+    pub fn new(scale: F) -> Result<Maxwell<F>, Error> {
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        Ok(Maxwell { scale })
+    }
+}
+
+impl<F> Distribution<F> for Maxwell<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let x: F = rng.sample(StandardNormal);
+        let y: F = rng.sample(StandardNormal);
+        let z: F = rng.sample(StandardNormal);
+        self.scale * (x * x + y * y + z * z).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_maxwell_invalid() {
+        assert_eq!(Maxwell::new(0.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(Maxwell::new(-1.).unwrap_err(), Error::ScaleTooSmall);
+        assert_eq!(Maxwell::new(core::f64::NAN).unwrap_err(), Error::ScaleTooSmall);
+    }
+
+    #[test]
+    fn test_maxwell_mean() {
+        let mut rng = crate::test::rng(491);
+        let a = 1.5;
+        let d = Maxwell::new(a).unwrap();
+        const N: usize = 20_000;
+        let mut sum = 0.;
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!(x >= 0.);
+            sum += x;
+        }
+        let mean = 2. * a * (2. / core::f64::consts::PI).sqrt();
+        assert_almost_eq!(sum / N as f64, mean, 0.02);
+    }
+}
@@ -11,9 +11,13 @@
 
 use crate::utils::ziggurat;
 use num_traits::Float;
-use crate::{ziggurat_tables, Distribution, Open01};
+use crate::{ziggurat_tables, Bernoulli, Distribution, Open01};
 use rand::Rng;
 use core::fmt;
+#[cfg(feature = "serde1")]
+use core::convert::TryFrom;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
 
 /// Samples floating-point numbers according to the normal distribution
 /// `N(0, 1)` (a.k.a. a standard normal, or Gaussian). This is equivalent to
@@ -110,8 +114,16 @@ impl Distribution<f64> for StandardNormal {
 /// println!("{} is from a N(2, 9) distribution", v)
 /// ```
 ///
+/// With the `serde1` feature, `Normal` is serialized as its `mean` and
+/// `std_dev`, and deserialized via [`Normal::new`].
+///
 /// [`StandardNormal`]: crate::StandardNormal
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde1",
+    serde(try_from = "NormalParams<F>", into = "NormalParams<F>")
+)]
 pub struct Normal<F>
 where F: Float, StandardNormal: Distribution<F>
 {
@@ -209,6 +221,35 @@ where F: Float, StandardNormal: Distribution<F>
     }
 }
 
+/// Serialized form of `Normal`, validated on deserialization
+#[cfg(feature = "serde1")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Normal")]
+struct NormalParams<F> {
+    mean: F,
+    std_dev: F,
+}
+
+#[cfg(feature = "serde1")]
+impl<F> From<Normal<F>> for NormalParams<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    fn from(d: Normal<F>) -> Self {
+        NormalParams { mean: d.mean, std_dev: d.std_dev }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<F> TryFrom<NormalParams<F>> for Normal<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    type Error = Error;
+
+    fn try_from(p: NormalParams<F>) -> Result<Self, Error> {
+        Normal::new(p.mean, p.std_dev)
+    }
+}
+
 
 /// The log-normal distribution `ln N(mean, std_dev**2)`.
 ///
@@ -225,7 +266,15 @@ where F: Float, StandardNormal: Distribution<F>
 /// let v = log_normal.sample(&mut rand::thread_rng());
 /// println!("{} is from an ln N(2, 9) distribution", v)
 /// ```
+///
+/// With the `serde1` feature, `LogNormal` is serialized as the (log-space)
+/// `mu` and `sigma`, and deserialized via [`LogNormal::new`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde1",
+    serde(try_from = "LogNormalParams<F>", into = "LogNormalParams<F>")
+)]
 pub struct LogNormal<F>
 where F: Float, StandardNormal: Distribution<F>
 {
@@ -312,6 +361,168 @@ where F: Float, StandardNormal: Distribution<F>
     }
 }
 
+/// Serialized form of `LogNormal`, validated on deserialization
+#[cfg(feature = "serde1")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LogNormal")]
+struct LogNormalParams<F> {
+    mu: F,
+    sigma: F,
+}
+
+#[cfg(feature = "serde1")]
+impl<F> From<LogNormal<F>> for LogNormalParams<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    fn from(d: LogNormal<F>) -> Self {
+        LogNormalParams { mu: d.norm.mean, sigma: d.norm.std_dev }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<F> TryFrom<LogNormalParams<F>> for LogNormal<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    type Error = Error;
+
+    fn try_from(p: LogNormalParams<F>) -> Result<Self, Error> {
+        LogNormal::new(p.mu, p.sigma)
+    }
+}
+
+/// The folded normal distribution `|N(mean, std_dev**2)|`.
+///
+/// If `X` is `N(mean, std_dev**2)` distributed, then `|X|` is folded normal
+/// distributed. With `mean = 0` this is the half-normal distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{FoldedNormal, Distribution};
+///
+/// // folded from mean 2, standard deviation 3
+/// let folded = FoldedNormal::new(2.0, 3.0).unwrap();
+/// let v = folded.sample(&mut rand::thread_rng());
+/// println!("{} is from an |N(2, 9)| distribution", v)
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FoldedNormal<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    norm: Normal<F>,
+}
+
+impl<F> FoldedNormal<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    /// Construct, from mean and standard deviation of the underlying Normal
+    /// distribution
+    ///
+    /// Parameters:
+    ///
+    /// -   mean (`μ`, unrestricted)
+    /// -   standard deviation (`σ`, must be finite)
+    #[inline]
+    pub fn new(mean: F, std_dev: F) -> Result<FoldedNormal<F>, Error> {
+        let norm = Normal::new(mean, std_dev)?;
+        Ok(FoldedNormal { norm })
+    }
+}
+
+impl<F> Distribution<F> for FoldedNormal<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.norm.sample(rng).abs()
+    }
+}
+
+/// A mixture of two normal distributions.
+///
+/// With probability `w` a sample is drawn from `N(mean1, std_dev1**2)`,
+/// otherwise from `N(mean2, std_dev2**2)`. This is the common bimodal case,
+/// sampled without dynamic dispatch or allocation.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{GaussianMixture2, Distribution};
+///
+/// // 30% around -2, 70% around 5
+/// let mix = GaussianMixture2::new(0.3, -2.0, 1.0, 5.0, 0.5).unwrap();
+/// let v = mix.sample(&mut rand::thread_rng());
+/// println!("{} is from a Gaussian mixture", v)
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GaussianMixture2<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    first: Bernoulli,
+    norm1: Normal<F>,
+    norm2: Normal<F>,
+}
+
+/// Error type returned from `GaussianMixture2::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GaussianMixture2Error {
+    /// The mixture weight is not in `[0, 1]`.
+    BadWeight,
+    /// The parameters of a component are invalid.
+    Normal(Error),
+}
+
+impl fmt::Display for GaussianMixture2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GaussianMixture2Error::BadWeight => f.write_str("weight is not in [0, 1] in Gaussian mixture"),
+            GaussianMixture2Error::Normal(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for GaussianMixture2Error {}
+
+impl<F> GaussianMixture2<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    /// Construct, from the weight of the first component and the mean and
+    /// standard deviation of each component
+    ///
+    /// Parameters:
+    ///
+    /// -   weight (`w`, probability of the first component, in `[0, 1]`)
+    /// -   means (`μ1`, `μ2`, unrestricted)
+    /// -   standard deviations (`σ1`, `σ2`, must be finite)
+    pub fn new(
+        w: F, mean1: F, std_dev1: F, mean2: F, std_dev2: F,
+    ) -> Result<GaussianMixture2<F>, GaussianMixture2Error> {
+        if !(w >= F::zero() && w <= F::one()) {
+            return Err(GaussianMixture2Error::BadWeight);
+        }
+        let first = Bernoulli::new(w.to_f64().unwrap())
+            .map_err(|_| GaussianMixture2Error::BadWeight)?;
+        let norm1 = Normal::new(mean1, std_dev1).map_err(GaussianMixture2Error::Normal)?;
+        let norm2 = Normal::new(mean2, std_dev2).map_err(GaussianMixture2Error::Normal)?;
+        Ok(GaussianMixture2 { first, norm1, norm2 })
+    }
+}
+
+impl<F> Distribution<F> for GaussianMixture2<F>
+where F: Float, StandardNormal: Distribution<F>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        if self.first.sample(rng) {
+            self.norm1.sample(rng)
+        } else {
+            self.norm2.sample(rng)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,4 +576,107 @@ mod tests {
         assert!(LogNormal::from_mean_cv(0.0, 1.0).is_err());
         assert!(LogNormal::from_mean_cv(1.0, -1.0).is_err());
     }
+
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn test_normal_serde() {
+        let d = Normal::new(2.0, 3.0).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"mean":2.0,"std_dev":3.0}"#);
+        let de: Normal<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!((de.mean(), de.std_dev()), (2.0, 3.0));
+
+        // The reconstructed distribution samples with the same mean
+        let mut rng = crate::test::rng(539);
+        const N: usize = 10_000;
+        let sum: f64 = (0..N).map(|_| de.sample(&mut rng)).sum();
+        assert_almost_eq!(sum / N as f64, 2.0, 0.1);
+
+        let d = LogNormal::new(0.5, 0.25).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"mu":0.5,"sigma":0.25}"#);
+        let de: LogNormal<f64> = serde_json::from_str(&json).unwrap();
+        let sum: f64 = (0..N).map(|_| de.sample(&mut rng)).sum();
+        // The mean is exp(μ + σ²/2)
+        assert_almost_eq!(sum / N as f64, (0.5f64 + 0.25 * 0.25 / 2.).exp(), 0.02);
+
+        // Deserialization validates the parameters
+        assert!(serde_json::from_str::<Normal<f64>>(r#"{"mean":0.0,"std_dev":null}"#).is_err());
+    }
+
+    #[test]
+    fn test_folded_normal() {
+        let folded = FoldedNormal::new(-3.0, 2.0).unwrap();
+        let mut rng = crate::test::rng(212);
+        for _ in 0..1000 {
+            assert!(folded.sample(&mut rng) >= 0.0);
+        }
+        assert!(FoldedNormal::new(1.0, core::f64::NAN).is_err());
+    }
+    #[test]
+    fn test_folded_normal_half() {
+        // With mean 0, this is the half-normal distribution
+        let folded = FoldedNormal::new(0.0, 2.0).unwrap();
+        let norm = Normal::new(0.0, 2.0).unwrap();
+        let mut rng1 = crate::test::rng(213);
+        let mut rng2 = crate::test::rng(213);
+        for _ in 0..100 {
+            assert_eq!(folded.sample(&mut rng1), norm.sample(&mut rng2).abs());
+        }
+
+        const N: usize = 20_000;
+        let mean = (0..N).map(|_| folded.sample(&mut rng1)).sum::<f64>() / N as f64;
+        // Half-normal mean is σ √(2/π)
+        assert_almost_eq!(mean, 2.0 * (2.0 / core::f64::consts::PI).sqrt(), 0.05);
+    }
+
+    #[test]
+    fn test_gaussian_mixture_invalid() {
+        assert_eq!(
+            GaussianMixture2::new(-0.1, 0.0, 1.0, 0.0, 1.0).unwrap_err(),
+            GaussianMixture2Error::BadWeight
+        );
+        assert_eq!(
+            GaussianMixture2::new(1.1, 0.0, 1.0, 0.0, 1.0).unwrap_err(),
+            GaussianMixture2Error::BadWeight
+        );
+        assert_eq!(
+            GaussianMixture2::new(core::f64::NAN, 0.0, 1.0, 0.0, 1.0).unwrap_err(),
+            GaussianMixture2Error::BadWeight
+        );
+        assert_eq!(
+            GaussianMixture2::new(0.5, 0.0, core::f64::INFINITY, 0.0, 1.0).unwrap_err(),
+            GaussianMixture2Error::Normal(Error::BadVariance)
+        );
+        assert!(GaussianMixture2::new(0.0, 0.0, 1.0, 0.0, 1.0).is_ok());
+        assert!(GaussianMixture2::new(1.0, 0.0, 1.0, 0.0, 1.0).is_ok());
+    }
+    #[test]
+    fn test_gaussian_mixture_bimodal() {
+        let mix = GaussianMixture2::new(0.3, -5.0, 1.0, 5.0, 1.0).unwrap();
+        let mut rng = crate::test::rng(214);
+        const N: usize = 20_000;
+        // Bins of width 2 over [-10, 10)
+        let mut hist = [0usize; 10];
+        let (mut n1, mut sum1, mut sum2) = (0, 0.0, 0.0);
+        for _ in 0..N {
+            let x: f64 = mix.sample(&mut rng);
+            if x < 0.0 {
+                n1 += 1;
+                sum1 += x;
+            } else {
+                sum2 += x;
+            }
+            if (-10.0..10.0).contains(&x) {
+                hist[((x + 10.0) / 2.0) as usize] += 1;
+            }
+        }
+        // Peaks around -5 and 5, with a trough in between
+        assert!(hist[2] > 10 * hist[5] && hist[7] > 10 * hist[4]);
+        assert!(hist[7] > 2 * hist[2]);
+
+        assert_almost_eq!(n1 as f64 / N as f64, 0.3, 0.02);
+        assert_almost_eq!(sum1 / n1 as f64, -5.0, 0.05);
+        assert_almost_eq!(sum2 / (N - n1) as f64, 5.0, 0.05);
+    }
 }
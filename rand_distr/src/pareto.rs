@@ -108,6 +108,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sample_heavy_tail() {
+        // For shape <= 1 the mean is infinite, but since u >= 2^-53 samples
+        // are bounded by scale * 2^(53 / shape) and thus finite.
+        let mut rng = crate::test::rng(509);
+        for &shape in &[1.0, 0.5, 0.1] {
+            let scale = 3.0;
+            let d = Pareto::new(scale, shape).unwrap();
+            const N: usize = 10_000;
+            let mut above = 0;
+            for _ in 0..N {
+                let r: f64 = d.sample(&mut rng);
+                assert!(r >= scale && r.is_finite());
+                if r > 2. * scale {
+                    above += 1;
+                }
+            }
+            // P(X > 2 scale) = 2^-shape
+            assert_almost_eq!(above as f64 / N as f64, 0.5f64.powf(shape), 0.02);
+        }
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<F: Float + Debug + Display + LowerExp, D: Distribution<F>>(
@@ -129,4 +129,28 @@ mod test {
             assert!(Triangular::new(min, max, mode).is_err());
         }
     }
+
+    #[test]
+    fn test_triangular_histogram() {
+        let (min, max, mode) = (2., 12., 4.5);
+        let distr = Triangular::new(min, max, mode).unwrap();
+        let mut rng = crate::test::rng(510);
+        const BINS: usize = 10;
+        let mut hist = [0usize; BINS];
+        for _ in 0..20_000 {
+            let x: f64 = distr.sample(&mut rng);
+            assert!((min..=max).contains(&x));
+            hist[(((x - min) / (max - min) * BINS as f64) as usize).min(BINS - 1)] += 1;
+        }
+        // The density increases up to the bin [4, 5) holding the mode, then
+        // decreases.
+        let peak = (0..BINS).max_by_key(|&i| hist[i]).unwrap();
+        assert_eq!(peak, 2);
+        for i in 0..peak {
+            assert!(hist[i] < hist[i + 1]);
+        }
+        for i in peak + 1..BINS - 1 {
+            assert!(hist[i] > hist[i + 1]);
+        }
+    }
 }
@@ -64,4 +64,26 @@ mod tests {
             assert_almost_eq!(x[0] * x[0] + x[1] * x[1], 1., 1e-15);
         }
     }
+
+    #[test]
+    fn isotropic() {
+        let mut rng = crate::test::rng(513);
+        const N: usize = 20_000;
+        // Count points per eighth of the circle, which would show clustering
+        // around the axes or the diagonals
+        let mut octants = [0usize; 8];
+        let mut mean = [0.; 2];
+        for _ in 0..N {
+            let x: [f64; 2] = UnitCircle.sample(&mut rng);
+            mean[0] += x[0] / N as f64;
+            mean[1] += x[1] / N as f64;
+            let angle = x[1].atan2(x[0]) + core::f64::consts::PI;
+            octants[((angle / core::f64::consts::FRAC_PI_4) as usize).min(7)] += 1;
+        }
+        assert_almost_eq!(mean[0], 0., 0.02);
+        assert_almost_eq!(mean[1], 0., 0.02);
+        for &count in &octants {
+            assert_almost_eq!(count as f64 / N as f64, 0.125, 0.01);
+        }
+    }
 }
@@ -59,4 +59,30 @@ mod tests {
             assert_almost_eq!(x[0] * x[0] + x[1] * x[1] + x[2] * x[2], 1., 1e-15);
         }
     }
+
+    #[test]
+    fn isotropic() {
+        let mut rng = crate::test::rng(513);
+        const N: usize = 20_000;
+        let mut mean = [0.; 3];
+        let mut second = [0.; 3];
+        // By Archimedes' theorem, each coordinate is uniform on [-1, 1]; in
+        // particular there is no clustering at the poles.
+        let mut z_hist = [0usize; 10];
+        for _ in 0..N {
+            let x: [f64; 3] = UnitSphere.sample(&mut rng);
+            for i in 0..3 {
+                mean[i] += x[i] / N as f64;
+                second[i] += x[i] * x[i] / N as f64;
+            }
+            z_hist[(((x[2] + 1.) * 5.) as usize).min(9)] += 1;
+        }
+        for i in 0..3 {
+            assert_almost_eq!(mean[i], 0., 0.02);
+            assert_almost_eq!(second[i], 1. / 3., 0.02);
+        }
+        for &count in &z_hist {
+            assert_almost_eq!(count as f64 / N as f64, 0.1, 0.01);
+        }
+    }
 }
@@ -102,6 +102,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shape_one_is_exponential() {
+        // Weibull(scale, 1) is the exponential distribution with mean `scale`
+        let scale = 2.5;
+        let d = Weibull::new(scale, 1.0).unwrap();
+        let mut rng = crate::test::rng(508);
+        const N: usize = 20_000;
+        let (mut sum, mut sum_sq, mut above_mean) = (0.0, 0.0, 0);
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!(x.is_finite() && x >= 0.);
+            sum += x;
+            sum_sq += x * x;
+            if x > scale {
+                above_mean += 1;
+            }
+        }
+        let mean = sum / N as f64;
+        assert_almost_eq!(mean, scale, 0.05 * scale);
+        assert_almost_eq!(sum_sq / N as f64 - mean * mean, scale * scale, 0.1 * scale * scale);
+        // P(X > mean) = 1/e
+        assert_almost_eq!(above_mean as f64 / N as f64, (-1f64).exp(), 0.01);
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<F: Float + core::fmt::Debug, D: Distribution<F>>(
@@ -0,0 +1,117 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Wigner semicircle distribution.
+
+use num_traits::Float;
+use crate::{Beta, Distribution, Open01};
+use rand::Rng;
+use core::fmt;
+
+/// The Wigner semicircle distribution `Wigner(r)`.
+///
+/// Samples lie in `[-r, r]`, with a density proportional to
+/// `sqrt(r² - x²)`, i.e. a semicircle of radius `r`. This is the limiting
+/// distribution of eigenvalues of many random symmetric matrices.
+///
+/// Sampling uses a `Beta(1.5, 1.5)` sample rescaled to `[-r, r]`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Wigner, Distribution};
+///
+/// let w = Wigner::new(2.0).unwrap();
+/// let v = w.sample(&mut rand::thread_rng());
+/// println!("{} is from a Wigner(2) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Wigner<F>
+where F: Float, Open01: Distribution<F>
+{
+    radius: F,
+    beta: Beta<F>,
+}
+
+/// Error type returned from `Wigner::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `radius <= 0`, infinite or `nan`.
+    RadiusInvalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::RadiusInvalid => "radius is not positive and finite in Wigner distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl<F> Wigner<F>
+where F: Float, Open01: Distribution<F>
+{
+    /// Construct a new `Wigner` distribution with the given `radius`.
+    pub fn new(radius: F) -> Result<Wigner<F>, Error> {
+        if !(radius > F::zero()) || radius.is_infinite() {
+            return Err(Error::RadiusInvalid);
+        }
+        let shape = F::from(1.5).unwrap();
+        let beta = Beta::new(shape, shape).unwrap();
+        Ok(Wigner { radius, beta })
+    }
+}
+
+impl<F> Distribution<F> for Wigner<F>
+where F: Float, Open01: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let x = self.beta.sample(rng);
+        self.radius * (F::from(2.).unwrap() * x - F::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wigner_invalid() {
+        assert_eq!(Wigner::new(0.).unwrap_err(), Error::RadiusInvalid);
+        assert_eq!(Wigner::new(-1.).unwrap_err(), Error::RadiusInvalid);
+        assert_eq!(Wigner::new(core::f64::NAN).unwrap_err(), Error::RadiusInvalid);
+        assert_eq!(Wigner::new(core::f64::INFINITY).unwrap_err(), Error::RadiusInvalid);
+    }
+
+    #[test]
+    fn test_wigner_semicircle() {
+        let mut rng = crate::test::rng(487);
+        let r = 3.0;
+        let d = Wigner::new(r).unwrap();
+        const N: usize = 20_000;
+        const BINS: usize = 6;
+        let mut hist = [0usize; BINS];
+        for _ in 0..N {
+            let x: f64 = d.sample(&mut rng);
+            assert!((-r..=r).contains(&x));
+            let bin = ((x + r) / (2. * r) * BINS as f64) as usize;
+            hist[bin.min(BINS - 1)] += 1;
+        }
+        // Symmetric, increasing towards the center
+        assert!(hist[0] < hist[1] && hist[1] < hist[2]);
+        assert!(hist[5] < hist[4] && hist[4] < hist[3]);
+        // The central bins each hold about 20.8% of the mass
+        for &count in &hist[2..4] {
+            assert_almost_eq!(count as f64 / N as f64, 0.208, 0.02);
+        }
+    }
+}
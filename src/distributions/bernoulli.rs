@@ -8,8 +8,9 @@
 
 //! The Bernoulli distribution.
 
-use crate::distributions::Distribution;
+use crate::distributions::{Distribution, Uniform};
 use crate::Rng;
+#[cfg(feature = "alloc")] use alloc::collections::BTreeMap;
 use core::{fmt, u64};
 
 #[cfg(feature = "serde1")]
@@ -107,6 +108,17 @@ impl Bernoulli {
         })
     }
 
+    /// Construct a new `Bernoulli` with the given `f32` probability of
+    /// success `p`.
+    ///
+    /// This is equivalent to `Bernoulli::new(f64::from(p))`; the conversion is
+    /// exact, thus the result is accurate for any `p` in `[0, 1]`. For
+    /// `p = 1.0` samples are always true and for `p = 0.0` always false.
+    #[inline]
+    pub fn new_f32(p: f32) -> Result<Bernoulli, BernoulliError> {
+        Bernoulli::new(f64::from(p))
+    }
+
     /// Construct a new `Bernoulli` with the probability of success of
     /// `numerator`-in-`denominator`. I.e. `new_ratio(2, 3)` will return
     /// a `Bernoulli` with a 2-in-3 chance, or about 67%, of returning `true`.
@@ -140,9 +152,207 @@ impl Distribution<bool> for Bernoulli {
     }
 }
 
+/// A distribution of booleans given by odds `a:b`.
+///
+/// Samples are `true` with probability `a / (a + b)`. Sampling compares a
+/// uniform integer from `0..a + b` against `a`, thus the probability is exact
+/// for all odds (unlike [`Bernoulli`], which is accurate to 2<sup>-64</sup>).
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::{Distribution, Odds};
+///
+/// // 3:1 odds, i.e. a 75% chance
+/// let d = Odds::new(3, 1).unwrap();
+/// let v = d.sample(&mut rand::thread_rng());
+/// println!("{} is from 3:1 odds", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Odds {
+    a: u64,
+    range: Uniform<u64>,
+}
+
+impl Odds {
+    /// Construct a new `Odds` distribution with odds `a:b` of success.
+    ///
+    /// If `a == 0` samples are always false; if `b == 0` they are always true.
+    /// Returns an error if `a == b == 0` or if `a + b` overflows a `u64`.
+    #[inline]
+    pub fn new(a: u64, b: u64) -> Result<Odds, BernoulliError> {
+        match a.checked_add(b) {
+            Some(total) if total > 0 => Ok(Odds { a, range: Uniform::new(0, total) }),
+            _ => Err(BernoulliError::InvalidProbability),
+        }
+    }
+}
+
+impl Distribution<bool> for Odds {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        self.range.sample(rng) < self.a
+    }
+}
+
+/// A stateful generator of booleans, each `true` with probability `p`, but
+/// without runs of equal values longer than `max_run`.
+///
+/// Each value is sampled from a [`Bernoulli`] distribution, unless the
+/// current run of equal values has reached `max_run`, in which case the
+/// opposite value is forced. This is useful e.g. for test patterns.
+///
+/// Forcing flips biases the frequency of `true` towards 1/2: runs of `true`
+/// have a mean length of `Lt = (1 - p^max_run) / (1 - p)` and runs of `false`
+/// of `Lf = (1 - q^max_run) / (1 - q)` with `q = 1 - p`, thus the long-run
+/// frequency of `true` is `Lt / (Lt + Lf)`. For example with `p = 0.3` and
+/// `max_run = 4` this is approximately 0.359; with `p = 0.5` there is no bias.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::RunLimitedBool;
+///
+/// let mut rng = rand::thread_rng();
+/// let mut bits = RunLimitedBool::new(0.9, 3).unwrap();
+/// let pattern: Vec<bool> = (0..20).map(|_| bits.next(&mut rng)).collect();
+/// assert!(pattern.windows(4).all(|w| w.iter().any(|&b| b != w[0])));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RunLimitedBool {
+    bernoulli: Bernoulli,
+    max_run: usize,
+    last: bool,
+    run: usize,
+}
+
+/// Error type returned from `RunLimitedBool::new`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunLimitedBoolError {
+    /// `p < 0` or `p > 1`.
+    InvalidProbability,
+    /// `max_run == 0`.
+    ZeroMaxRun,
+}
+
+impl fmt::Display for RunLimitedBoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RunLimitedBoolError::InvalidProbability => "p is outside [0, 1] in RunLimitedBool",
+            RunLimitedBoolError::ZeroMaxRun => "max_run is 0 in RunLimitedBool",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for RunLimitedBoolError {}
+
+impl RunLimitedBool {
+    /// Construct a new `RunLimitedBool` with probability `p` of `true` and
+    /// maximum run length `max_run`.
+    ///
+    /// Returns an error if `p < 0`, `p > 1` or `max_run == 0`.
+    #[inline]
+    pub fn new(p: f64, max_run: usize) -> Result<RunLimitedBool, RunLimitedBoolError> {
+        if max_run == 0 {
+            return Err(RunLimitedBoolError::ZeroMaxRun);
+        }
+        let bernoulli = Bernoulli::new(p).map_err(|_| RunLimitedBoolError::InvalidProbability)?;
+        Ok(RunLimitedBool {
+            bernoulli,
+            max_run,
+            last: false,
+            run: 0,
+        })
+    }
+
+    /// Generate the next value.
+    pub fn next<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        let value = if self.run == self.max_run {
+            !self.last
+        } else {
+            self.bernoulli.sample(rng)
+        };
+        if self.run > 0 && value == self.last {
+            self.run += 1;
+        } else {
+            self.last = value;
+            self.run = 1;
+        }
+        value
+    }
+}
+
+/// A cache of [`Bernoulli`] distributions constructed from ratios.
+///
+/// [`BernoulliCache::sample`] behaves like [`Rng::gen_ratio`], but constructs
+/// the distribution for each `(numerator, denominator)` pair only once. This
+/// is useful when repeatedly sampling from a few fixed probabilities which
+/// are not known at compile time.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::BernoulliCache;
+///
+/// let mut rng = rand::thread_rng();
+/// let mut cache = BernoulliCache::new();
+/// for i in 0..10 {
+///     let (num, denom) = if i % 2 == 0 { (1, 3) } else { (2, 5) };
+///     println!("{}", cache.sample(&mut rng, num, denom));
+/// }
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default)]
+pub struct BernoulliCache {
+    cache: BTreeMap<(u32, u32), Bernoulli>,
+}
+
+#[cfg(feature = "alloc")]
+impl BernoulliCache {
+    /// Construct an empty cache
+    pub fn new() -> BernoulliCache {
+        BernoulliCache { cache: BTreeMap::new() }
+    }
+
+    /// Return a bool with a probability of `numerator/denominator` of being
+    /// true.
+    ///
+    /// This samples the same value as [`Rng::gen_ratio`].
+    ///
+    /// # Panics
+    ///
+    /// If `denominator == 0` or `numerator > denominator`.
+    pub fn sample<R: Rng + ?Sized>(&mut self, rng: &mut R, numerator: u32, denominator: u32) -> bool {
+        let d = *self.cache.entry((numerator, denominator))
+            .or_insert_with(|| Bernoulli::from_ratio(numerator, denominator).unwrap());
+        d.sample(rng)
+    }
+
+    /// The number of cached distributions
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if no distributions are cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Remove all cached distributions
+    pub fn clear(&mut self) {
+        self.cache.clear()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Bernoulli;
+    use super::{Bernoulli, Odds, RunLimitedBool, RunLimitedBoolError};
+    #[cfg(feature = "alloc")] use super::BernoulliCache;
     use crate::distributions::Distribution;
     use crate::Rng;
 
@@ -171,6 +381,23 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_new_f32() {
+        let mut rng = crate::test::rng(519);
+        let always_false = Bernoulli::new_f32(0.0).unwrap();
+        let always_true = Bernoulli::new_f32(1.0).unwrap();
+        assert!((0..100).all(|_| !rng.sample(always_false)));
+        assert!((0..100).all(|_| rng.sample(always_true)));
+        assert_eq!(Bernoulli::new_f32(0.1).unwrap().p_int, Bernoulli::new(0.1f32 as f64).unwrap().p_int);
+        assert!(Bernoulli::new_f32(1.5).is_err());
+        assert!(Bernoulli::new_f32(core::f32::NAN).is_err());
+
+        let d = Bernoulli::new_f32(0.5).unwrap();
+        const N: u32 = 10_000;
+        let sum = (0..N).filter(|_| rng.sample(d)).count() as f64;
+        assert!((sum / N as f64 - 0.5).abs() < 0.02);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_average() {
@@ -211,4 +438,89 @@ mod test {
             true, false, false, true, false, false, true, true, true, true
         ]);
     }
+
+    #[test]
+    fn test_odds() {
+        let mut rng = crate::test::rng(515);
+        let d = Odds::new(3, 1).unwrap();
+        const N: u32 = 10_000;
+        let sum: u32 = (0..N).map(|_| d.sample(&mut rng) as u32).sum();
+        let ratio = sum as f64 / N as f64;
+        assert!((ratio - 0.75).abs() < 0.015, "{}", ratio);
+
+        let never = Odds::new(0, 5).unwrap();
+        let always = Odds::new(core::u64::MAX, 0).unwrap();
+        for _ in 0..100 {
+            assert!(!never.sample(&mut rng));
+            assert!(always.sample(&mut rng));
+        }
+
+        assert!(Odds::new(0, 0).is_err());
+        assert!(Odds::new(core::u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_run_limited_bool() {
+        let mut rng = crate::test::rng(541);
+        for &(p, max_run, freq) in &[(0.3, 4, 0.3587), (0.8, 3, 0.6630), (0.5, 1, 0.5)] {
+            let mut d = RunLimitedBool::new(p, max_run).unwrap();
+            const N: usize = 20_000;
+            let (mut last, mut run, mut sum) = (false, 0, 0);
+            for _ in 0..N {
+                let b = d.next(&mut rng);
+                run = if run > 0 && b == last { run + 1 } else { 1 };
+                last = b;
+                assert!(run <= max_run);
+                sum += b as usize;
+            }
+            // The frequency of `true` matches the documented bias
+            let ratio = sum as f64 / N as f64;
+            assert!((ratio - freq).abs() < 0.015, "{}", ratio);
+        }
+
+        // With p = 0, every third value is a forced `true`
+        let mut d = RunLimitedBool::new(0.0, 2).unwrap();
+        let mut v = [true; 6];
+        for b in v.iter_mut() {
+            *b = d.next(&mut rng);
+        }
+        assert_eq!(v, [false, false, true, false, false, true]);
+
+        assert_eq!(
+            RunLimitedBool::new(1.5, 2).unwrap_err(),
+            RunLimitedBoolError::InvalidProbability
+        );
+        assert_eq!(RunLimitedBool::new(0.5, 0).unwrap_err(), RunLimitedBoolError::ZeroMaxRun);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bernoulli_cache() {
+        let mut rng1 = crate::test::rng(496);
+        let mut rng2 = crate::test::rng(496);
+        let ratios = [(1, 3), (2, 5), (0, 7), (4, 4)];
+        let mut cache = BernoulliCache::new();
+        assert!(cache.is_empty());
+        let mut sums = [0u32; 4];
+        for i in 0..4000 {
+            let (num, denom) = ratios[i % 4];
+            let b = cache.sample(&mut rng1, num, denom);
+            assert_eq!(b, rng2.gen_ratio(num, denom));
+            sums[i % 4] += b as u32;
+        }
+        assert_eq!(cache.len(), 4);
+        assert!(sums[0] > 270 && sums[0] < 400);
+        assert!(sums[1] > 340 && sums[1] < 460);
+        assert_eq!((sums[2], sums[3]), (0, 1000));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn test_bernoulli_cache_invalid() {
+        BernoulliCache::new().sample(&mut crate::test::rng(497), 3, 2);
+    }
 }
@@ -0,0 +1,82 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::distributions::{Distribution, Uniform};
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample vectors of random length.
+///
+/// [`BoundedVec::new`] constructs a distribution which samples a length
+/// uniformly from `0..=max_len`, then fills a vector of that length with
+/// samples from the element distribution. This is useful e.g. for generating
+/// inputs for fuzzing or property testing.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::{BoundedVec, Standard};
+///
+/// let v: Vec<u8> = rand::thread_rng().sample(BoundedVec::new(16, Standard));
+/// assert!(v.len() <= 16);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BoundedVec<D> {
+    len: Uniform<usize>,
+    distr: D,
+}
+
+impl<D> BoundedVec<D> {
+    /// Create a new `BoundedVec` sampling up to `max_len` elements from
+    /// `distr`.
+    pub fn new(max_len: usize, distr: D) -> BoundedVec<D> {
+        BoundedVec {
+            len: Uniform::new_inclusive(0, max_len),
+            distr,
+        }
+    }
+}
+
+impl<T, D: Distribution<T>> Distribution<Vec<T>> for BoundedVec<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<T> {
+        let len = rng.sample(self.len);
+        (0..len).map(|_| self.distr.sample(rng)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::Standard;
+
+    #[test]
+    fn test_bounded_vec() {
+        let mut rng = crate::test::rng(492);
+        let distr = BoundedVec::new(10, Standard);
+        let mut empty = 0;
+        let mut full = 0;
+        for _ in 0..1000 {
+            let v: Vec<u32> = rng.sample(distr);
+            assert!(v.len() <= 10);
+            match v.len() {
+                0 => empty += 1,
+                10 => full += 1,
+                _ => (),
+            }
+        }
+        assert!(empty > 0 && full > 0);
+
+        let distr = BoundedVec::new(0, Uniform::new(0, 10));
+        assert!(rng.sample::<Vec<i32>, _>(&distr).is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::boxed::Box;
+use alloc::vec;
+
+use crate::distributions::Distribution;
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample boxed byte slices of a fixed length.
+///
+/// Each sample is a newly allocated `Box<[u8]>` of exactly `len` bytes,
+/// filled via [`Rng::fill`].
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::BoxedBytes;
+///
+/// let bytes: Box<[u8]> = rand::thread_rng().sample(BoxedBytes::new(32));
+/// assert_eq!(bytes.len(), 32);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BoxedBytes {
+    len: usize,
+}
+
+impl BoxedBytes {
+    /// Create a new `BoxedBytes` sampling slices of `len` bytes.
+    pub fn new(len: usize) -> BoxedBytes {
+        BoxedBytes { len }
+    }
+}
+
+impl Distribution<Box<[u8]>> for BoxedBytes {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Box<[u8]> {
+        let mut bytes = vec![0u8; self.len].into_boxed_slice();
+        rng.fill(&mut bytes[..]);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_boxed_bytes() {
+        let mut rng = crate::test::rng(501);
+        for &len in &[0, 1, 7, 64] {
+            let bytes: Box<[u8]> = rng.sample(BoxedBytes::new(len));
+            assert_eq!(bytes.len(), len);
+        }
+
+        let distr = BoxedBytes::new(16);
+        let a: Box<[u8]> = rng.sample(distr);
+        let b: Box<[u8]> = rng.sample(distr);
+        assert_ne!(a, b);
+    }
+}
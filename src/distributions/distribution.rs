@@ -189,6 +189,78 @@ where
     }
 }
 
+/// Combine two distributions into a distribution over pairs.
+///
+/// Each sample is a tuple of independent samples from `d1` and `d2`, drawn in
+/// this order. This allows sampling heterogeneous tuples without defining a
+/// custom distribution type. See also [`zip3`].
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::{zip2, Standard, Uniform};
+///
+/// let (x, n): (f64, u32) = rand::thread_rng().sample(zip2(Standard, Uniform::new(0, 10)));
+/// assert!(x < 1.0 && n < 10);
+/// ```
+pub fn zip2<D1, D2>(d1: D1, d2: D2) -> Zip2<D1, D2> {
+    Zip2 { d1, d2 }
+}
+
+/// Combine three distributions into a distribution over triples.
+///
+/// As [`zip2`], but for three elements.
+pub fn zip3<D1, D2, D3>(d1: D1, d2: D2, d3: D3) -> Zip3<D1, D2, D3> {
+    Zip3 { d1, d2, d3 }
+}
+
+/// A distribution over pairs, sampling each element from its own distribution.
+///
+/// This `struct` is created by the [`zip2`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Zip2<D1, D2> {
+    d1: D1,
+    d2: D2,
+}
+
+impl<A, B, D1, D2> Distribution<(A, B)> for Zip2<D1, D2>
+where
+    D1: Distribution<A>,
+    D2: Distribution<B>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (A, B) {
+        let a = self.d1.sample(rng);
+        let b = self.d2.sample(rng);
+        (a, b)
+    }
+}
+
+/// A distribution over triples, sampling each element from its own
+/// distribution.
+///
+/// This `struct` is created by the [`zip3`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Zip3<D1, D2, D3> {
+    d1: D1,
+    d2: D2,
+    d3: D3,
+}
+
+impl<A, B, C, D1, D2, D3> Distribution<(A, B, C)> for Zip3<D1, D2, D3>
+where
+    D1: Distribution<A>,
+    D2: Distribution<B>,
+    D3: Distribution<C>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (A, B, C) {
+        let a = self.d1.sample(rng);
+        let b = self.d2.sample(rng);
+        let c = self.d3.sample(rng);
+        (a, b, c)
+    }
+}
+
 /// `String` sampler
 ///
 /// Sampling a `String` of random characters is not quite the same as collecting
@@ -234,6 +306,46 @@ mod tests {
         assert!(val >= 15 && val <= 20);
     }
 
+    #[test]
+    fn test_distributions_map_die() {
+        let die = Uniform::new(0u32, 6).map(|x| x + 1);
+
+        let mut rng = crate::test::rng(517);
+        let mut seen = [false; 6];
+        for _ in 0..100 {
+            let x = rng.sample(&die);
+            assert!((1..=6).contains(&x));
+            seen[x as usize - 1] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        // The mapped distribution is reusable by reference
+        assert!((&die).sample_iter(&mut rng).take(100).all(|x| (1..=6).contains(&x)));
+    }
+
+    #[test]
+    fn test_distributions_zip() {
+        use crate::distributions::{zip2, zip3, Bernoulli};
+
+        let mut rng = crate::test::rng(513);
+        let dist = zip2(Standard, Uniform::new(0u32, 10));
+        for _ in 0..100 {
+            let (x, n): (f64, u32) = dist.sample(&mut rng);
+            assert!((0.0..1.0).contains(&x) && n < 10);
+        }
+
+        // Elements are sampled in order
+        let mut rng1 = crate::test::rng(514);
+        let mut rng2 = crate::test::rng(514);
+        let (x, n): (f64, u32) = dist.sample(&mut rng1);
+        assert_eq!(x, rng2.gen::<f64>());
+        assert_eq!(n, rng2.sample(Uniform::new(0u32, 10)));
+
+        let dist = zip3(Uniform::new(-3i8, -1), Bernoulli::new(1.0).unwrap(), Alphanumeric);
+        let (a, b, c) = dist.sample(&mut rng);
+        assert!((-3..-1).contains(&a) && b && (c as char).is_ascii_alphanumeric());
+    }
+
     #[test]
     fn test_make_an_iter() {
         fn ten_dice_rolls_other_than_five<R: Rng>(
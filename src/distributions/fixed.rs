@@ -0,0 +1,79 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixed-point fractions.
+
+use crate::distributions::{Distribution, Standard};
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// An unsigned fixed-point fraction in `[0, 1)` with 32 fractional bits.
+///
+/// The value represented is `self.0 / 2^32`. Sampling via [`Standard`] is
+/// uniform over all `2^32` values, and arithmetic on the raw `u32` is exact,
+/// thus simulations built on `UFixed32` are reproducible independently of
+/// floating-point rounding behaviour.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::fixed::UFixed32;
+///
+/// let x: UFixed32 = rand::thread_rng().gen();
+/// let f = x.to_f64();
+/// assert!(0.0 <= f && f < 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct UFixed32(pub u32);
+
+impl UFixed32 {
+    /// Convert to `f64`.
+    ///
+    /// This conversion is exact.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 * (1.0 / 4_294_967_296.0)
+    }
+}
+
+impl Distribution<UFixed32> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UFixed32 {
+        UFixed32(rng.next_u32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ufixed32_to_f64() {
+        assert_eq!(UFixed32(0).to_f64(), 0.0);
+        assert_eq!(UFixed32(1 << 31).to_f64(), 0.5);
+        assert_eq!(UFixed32(1).to_f64(), 1.0 / 4_294_967_296.0);
+        assert!(UFixed32(core::u32::MAX).to_f64() < 1.0);
+    }
+
+    #[test]
+    fn test_ufixed32_matches_float() {
+        // `f32` samples use the high 24 bits of the same `u32`, so the two
+        // agree up to `f32` resolution.
+        let mut rng1 = crate::test::rng(507);
+        let mut rng2 = crate::test::rng(507);
+        for _ in 0..1000 {
+            let x: UFixed32 = rng1.gen();
+            let f: f32 = rng2.gen();
+            let diff = x.to_f64() - f as f64;
+            assert!((0.0..1.0 / 16_777_216.0).contains(&diff));
+        }
+    }
+}
@@ -169,6 +169,21 @@ float_impls! { f64x4, u64x4, f64, u64, 52, 1023 }
 #[cfg(feature = "simd_support")]
 float_impls! { f64x8, u64x8, f64, u64, 52, 1023 }
 
+#[cfg(feature = "half")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "half")))]
+impl Distribution<half::f16> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> half::f16 {
+        // Multiply-based method; 11 random bits; [0, 1) interval.
+        // The product is exactly representable in both `f32` and `f16`, thus
+        // the conversion does not round.
+        let precision = 10 + 1;
+        let scale = 1.0 / ((1u32 << precision) as f32);
+
+        let value = rng.next_u32() >> (32 - precision);
+        half::f16::from_f32(scale * value as f32)
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -256,6 +271,41 @@ mod tests {
     #[cfg(feature = "simd_support")]
     test_f64! { f64x8_edge_cases, f64x8, f64x8::splat(0.0), f64x8::splat(EPSILON64) }
 
+    #[test]
+    fn test_float_arrays() {
+        // Arrays are sampled element-wise, thus match scalar sampling
+        let mut rng = crate::test::rng(480);
+        for _ in 0..100 {
+            let a: [f32; 8] = rng.gen();
+            assert!(a.iter().all(|x| x.is_finite() && (0.0..1.0).contains(x)));
+            let b: [f64; 4] = rng.gen();
+            assert!(b.iter().all(|x| x.is_finite() && (0.0..1.0).contains(x)));
+        }
+
+        let mut rng1 = crate::test::rng(481);
+        let mut rng2 = crate::test::rng(481);
+        let a: [f64; 4] = rng1.gen();
+        for x in a.iter() {
+            assert_eq!(*x, rng2.gen::<f64>());
+        }
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_f16() {
+        let mut rng = crate::test::rng(517);
+        for _ in 0..1000 {
+            let x: half::f16 = rng.gen();
+            let x = x.to_f32();
+            assert!(x.is_finite() && (0.0..1.0).contains(&x));
+        }
+
+        let mut zero = StepRng::new(0, 0);
+        assert_eq!(zero.gen::<half::f16>().to_f32(), 0.0);
+        let mut max = StepRng::new(!0, 0);
+        assert_eq!(max.gen::<half::f16>().to_f32(), 1.0 - 1.0 / 2048.0);
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq, D: Distribution<T>>(
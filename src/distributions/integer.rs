@@ -185,6 +185,31 @@ mod tests {
         rng.sample::<u128, _>(Standard);
     }
 
+    #[test]
+    fn test_nonzero_integers() {
+        let mut rng = crate::test::rng(520);
+        // Zero is drawn regularly for u8, thus rejection is exercised
+        let mut seen = [false; 256];
+        for _ in 0..10_000 {
+            let x: NonZeroU8 = rng.gen();
+            seen[x.get() as usize] = true;
+        }
+        assert!(!seen[0] && seen[1..].iter().all(|&x| x));
+
+        for _ in 0..1000 {
+            assert_ne!(rng.gen::<NonZeroU16>().get(), 0);
+            assert_ne!(rng.gen::<NonZeroU32>().get(), 0);
+            assert_ne!(rng.gen::<NonZeroU64>().get(), 0);
+            #[cfg(not(target_os = "emscripten"))]
+            assert_ne!(rng.gen::<NonZeroU128>().get(), 0);
+            assert_ne!(rng.gen::<NonZeroUsize>().get(), 0);
+        }
+
+        // Zero outputs are resampled
+        let mut rng = crate::rngs::mock::StepRng::new(0, 1);
+        assert_eq!(rng.gen::<NonZeroU32>().get(), 1);
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq>(zero: T, expected: &[T])
@@ -0,0 +1,103 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random JSON scalar values.
+
+use serde_json::{Number, Value};
+use std::string::String;
+
+use crate::distributions::{Distribution, Standard};
+use crate::Rng;
+
+/// Maximum length, in `char`s, of strings sampled by `JsonScalar`
+const MAX_STRING_LEN: usize = 16;
+
+/// Sample a random JSON scalar, i.e. a [`serde_json::Value`] which is neither
+/// an array nor an object.
+///
+/// Each of `null`, booleans, numbers and strings is chosen with probability
+/// 1/4. Numbers are equally likely to be integers, uniform over all `i64`
+/// values, or finite floats uniform in `[-1e6, 1e6)`. Strings have a length
+/// uniform in `0..=16`, with `char`s sampled via [`Standard`], and thus
+/// include control characters and characters requiring escapes.
+///
+/// This is intended for fuzzing JSON consumers.
+///
+/// # Example
+///
+/// ```
+/// use rand::{Rng, thread_rng};
+/// use rand::distributions::JsonScalar;
+///
+/// let value: serde_json::Value = thread_rng().sample(JsonScalar);
+/// println!("{}", value);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "json")))]
+pub struct JsonScalar;
+
+impl Distribution<Value> for JsonScalar {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Value {
+        match rng.gen_range(0..4) {
+            0 => Value::Null,
+            1 => Value::Bool(rng.gen()),
+            2 => {
+                if rng.gen() {
+                    Value::Number(rng.gen::<i64>().into())
+                } else {
+                    let x = rng.gen_range(-1e6..1e6);
+                    // `from_f64` only fails for non-finite values
+                    Value::Number(Number::from_f64(x).unwrap())
+                }
+            }
+            _ => {
+                let len = rng.gen_range(0..=MAX_STRING_LEN);
+                let s: String = rng.sample_iter::<char, _>(Standard).take(len).collect();
+                Value::String(s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_scalar() {
+        let mut rng = crate::test::rng(539);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let value: Value = rng.sample(JsonScalar);
+            let i = match value {
+                Value::Null => 0,
+                Value::Bool(_) => 1,
+                Value::Number(ref n) if n.is_i64() => 2,
+                Value::Number(_) => 3,
+                Value::String(ref s) => {
+                    assert!(s.chars().count() <= MAX_STRING_LEN);
+                    4
+                }
+                _ => panic!("not a scalar: {}", value),
+            };
+            seen[i] = true;
+
+            // The output is valid JSON. Without serde_json's `float_roundtrip`
+            // feature, parsed floats may differ in the last bit.
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed: Value = serde_json::from_str(&json).unwrap();
+            if i == 3 {
+                let (a, b) = (value.as_f64().unwrap(), parsed.as_f64().unwrap());
+                assert!((a - b).abs() <= 1e-9 * a.abs());
+            } else {
+                assert_eq!(parsed, value);
+            }
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+}
@@ -44,7 +44,7 @@
 //!
 //! [`Alphanumeric`] is a simple distribution to sample random letters and
 //! numbers of the `char` type; in contrast [`Standard`] may sample any valid
-//! `char`.
+//! `char`. [`Emoji`] samples from a curated set of emoji.
 //!
 //!
 //! # Uniform numeric ranges
@@ -94,12 +94,24 @@
 //! [`statrs`]: https://crates.io/crates/statrs
 
 mod bernoulli;
+#[cfg(feature = "alloc")]
+mod bounded_vec;
+#[cfg(feature = "alloc")]
+mod boxed_bytes;
 mod distribution;
 mod float;
 mod integer;
+#[cfg(feature = "json")]
+mod json;
 mod other;
+#[cfg(feature = "std")]
+mod random_map;
+#[cfg(feature = "std")]
+mod random_path;
+mod random_slice;
 mod slice;
-mod utils;
+mod sparse_bits;
+pub mod utils;
 #[cfg(feature = "alloc")]
 mod weighted_index;
 
@@ -107,6 +119,7 @@ mod weighted_index;
 pub mod hidden_export {
     pub use super::float::IntoFloat; // used by rand_distr
 }
+pub mod fixed;
 pub mod uniform;
 #[deprecated(
     since = "0.8.0",
@@ -116,13 +129,31 @@ pub mod uniform;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod weighted;
 
-pub use self::bernoulli::{Bernoulli, BernoulliError};
-pub use self::distribution::{Distribution, DistIter, DistMap};
+pub use self::bernoulli::{
+    Bernoulli, BernoulliError, Odds, RunLimitedBool, RunLimitedBoolError,
+};
+#[cfg(feature = "alloc")]
+pub use self::bernoulli::BernoulliCache;
+#[cfg(feature = "alloc")]
+pub use self::bounded_vec::BoundedVec;
+#[cfg(feature = "alloc")]
+pub use self::boxed_bytes::BoxedBytes;
+pub use self::distribution::{zip2, zip3, Distribution, DistIter, DistMap, Zip2, Zip3};
 #[cfg(feature = "alloc")]
 pub use self::distribution::DistString;
 pub use self::float::{Open01, OpenClosed01};
-pub use self::other::Alphanumeric;
+#[cfg(feature = "json")]
+pub use self::json::JsonScalar;
+pub use self::other::{Alphanumeric, AsciiByte, Digraph, Emoji};
+#[cfg(feature = "std")]
+pub use self::random_map::RandomMap;
+#[cfg(feature = "std")]
+pub use self::random_path::RandomPath;
+pub use self::random_slice::RandomSlice;
 pub use self::slice::Slice;
+pub use self::sparse_bits::SparseBits;
+#[cfg(feature = "alloc")]
+pub use self::slice::GraphemePalette;
 #[doc(inline)]
 pub use self::uniform::Uniform;
 #[cfg(feature = "alloc")]
@@ -142,6 +173,9 @@ use crate::Rng;
 ///
 /// * Integers (`i32`, `u32`, `isize`, `usize`, etc.): Uniformly distributed
 ///   over all values of the type.
+/// * Non-zero integers (`NonZeroU32`, `NonZeroUsize`, etc.): Uniformly
+///   distributed over all non-zero values of the type; zeros are rejected and
+///   resampled.
 /// * `char`: Uniformly distributed over all Unicode scalar values, i.e. all
 ///   code points in the range `0...0x10_FFFF`, except for the range
 ///   `0xD800...0xDFFF` (the surrogate code points). This includes
@@ -149,8 +183,17 @@ use crate::Rng;
 /// * `bool`: Generates `false` or `true`, each with probability 0.5.
 /// * Floating point types (`f32` and `f64`): Uniformly distributed in the
 ///   half-open range `[0, 1)`. See notes below.
+/// * `half::f16` (with the `half` feature): Uniformly distributed in the
+///   half-open range `[0, 1)`, using 11 random bits.
 /// * Wrapping integers (`Wrapping<T>`), besides the type identical to their
 ///   normal integer variants.
+/// * [`fixed::UFixed32`]: Uniformly distributed over all `2^32` fractions in
+///   `[0, 1)`.
+/// * `Duration`: Seconds are uniformly distributed over all `u64` values and
+///   nanoseconds over `0..1_000_000_000`. Use [`Uniform`] for bounded
+///   durations.
+/// * `Ipv4Addr` and `Ipv6Addr` (with `std`): Uniformly distributed over all
+///   addresses. `IpAddr` is `V4` or `V6`, each with probability 0.5.
 ///
 /// The `Standard` distribution also supports generation of the following
 /// compound types where all component types are supported:
@@ -167,6 +210,8 @@ use crate::Rng;
 ///     any element type supported by `Standard`.
 /// *   `Option<T>` first generates a `bool`, and if true generates and returns
 ///     `Some(value)` where `value: T`, otherwise returning `None`.
+/// *   `Bound<T>` is `Included(value)`, `Excluded(value)` or `Unbounded`, each
+///     with probability 1/3.
 ///
 /// ## Custom implementations
 ///
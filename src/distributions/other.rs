@@ -9,7 +9,14 @@
 //! The implementations of the `Standard` distribution for other built-in types.
 
 use core::char;
+use core::cmp::Reverse;
 use core::num::Wrapping;
+use core::ops::Bound;
+#[cfg(feature = "control_flow")]
+use core::ops::ControlFlow;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 
@@ -65,6 +72,82 @@ use std::mem::{self, MaybeUninit};
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct Alphanumeric;
 
+/// Sample a `u8`, uniformly distributed over all 7-bit ASCII codes: `0..=127`.
+///
+/// Unlike [`Alphanumeric`], this includes punctuation, whitespace and control
+/// characters. This may be useful e.g. for fuzzing protocols which must be
+/// 7-bit clean.
+///
+/// # Example
+///
+/// ```
+/// use rand::{Rng, thread_rng};
+/// use rand::distributions::AsciiByte;
+///
+/// let byte = thread_rng().sample(AsciiByte);
+/// assert!(byte.is_ascii());
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AsciiByte;
+
+/// Sample a `char`, uniformly distributed over a curated set of emoji.
+///
+/// The set consists of the following ranges of Unicode codepoints, all of
+/// which are assigned emoji with default emoji presentation:
+///
+/// - `U+1F600..=U+1F64F` (emoticons)
+/// - `U+1F950..=U+1F96B` (food and drink)
+/// - `U+1F980..=U+1F997` (animals)
+///
+/// # Example
+///
+/// ```
+/// use rand::{Rng, thread_rng};
+/// use rand::distributions::Emoji;
+///
+/// let avatar: String = thread_rng().sample_iter(Emoji).take(3).collect();
+/// println!("Your avatar: {}", avatar);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Emoji;
+
+/// Inclusive codepoint ranges sampled by `Emoji`
+const EMOJI_RANGES: [(u32, u32); 3] = [
+    (0x1F600, 0x1F64F),
+    (0x1F950, 0x1F96B),
+    (0x1F980, 0x1F997),
+];
+
+/// Sample a pair of `char`s, each independently from the distribution `D`.
+///
+/// This is a building block for placeholder text, e.g. digraphs over a
+/// configured alphabet.
+///
+/// # Example
+///
+/// ```
+/// use rand::{Rng, thread_rng};
+/// use rand::distributions::{Digraph, Uniform};
+///
+/// let digraph = Digraph::new(Uniform::new_inclusive('a', 'z'));
+/// let (a, b) = thread_rng().sample(digraph);
+/// assert!(a.is_ascii_lowercase() && b.is_ascii_lowercase());
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Digraph<D> {
+    chars: D,
+}
+
+impl<D: Distribution<char>> Digraph<D> {
+    /// Create a new `Digraph` sampling both `char`s from `chars`.
+    pub fn new(chars: D) -> Digraph<D> {
+        Digraph { chars }
+    }
+}
+
 
 // ----- Implementations of distributions -----
 
@@ -121,6 +204,54 @@ impl Distribution<u8> for Alphanumeric {
     }
 }
 
+impl Distribution<u8> for AsciiByte {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        // Use the most significant bits, which for small RNGs are usually of
+        // higher quality.
+        (rng.next_u32() >> (32 - 7)) as u8
+    }
+}
+
+impl Distribution<char> for Emoji {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let total: u32 = EMOJI_RANGES.iter().map(|&(lo, hi)| hi - lo + 1).sum();
+        let mut n = rng.gen_range(0..total);
+        for &(lo, hi) in EMOJI_RANGES.iter() {
+            let len = hi - lo + 1;
+            if n < len {
+                return char::from_u32(lo + n).unwrap();
+            }
+            n -= len;
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DistString for Emoji {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
+        string.extend(self.sample_iter(rng).take(len));
+    }
+}
+
+impl<D: Distribution<char>> Distribution<(char, char)> for Digraph<D> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (char, char) {
+        (self.chars.sample(rng), self.chars.sample(rng))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DistString for AsciiByte {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
+        unsafe {
+            let v = string.as_mut_vec();
+            v.extend(self.sample_iter(rng).take(len));
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl DistString for Alphanumeric {
     fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
@@ -249,6 +380,83 @@ where Standard: Distribution<T>
     }
 }
 
+impl<T> Distribution<Reverse<T>> for Standard
+where Standard: Distribution<T>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Reverse<T> {
+        Reverse(rng.gen())
+    }
+}
+
+impl<T> Distribution<Bound<T>> for Standard
+where Standard: Distribution<T>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Bound<T> {
+        match rng.gen_range(0..3) {
+            0 => Bound::Included(rng.gen()),
+            1 => Bound::Excluded(rng.gen()),
+            _ => Bound::Unbounded,
+        }
+    }
+}
+
+impl Distribution<Duration> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Duration {
+        let secs = rng.gen();
+        let nanos = rng.gen_range(0..1_000_000_000);
+        Duration::new(secs, nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl Distribution<Ipv4Addr> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv4Addr {
+        Ipv4Addr::from(rng.gen::<u32>())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl Distribution<Ipv6Addr> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv6Addr {
+        Ipv6Addr::from(rng.gen::<u128>())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl Distribution<IpAddr> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IpAddr {
+        if rng.gen::<bool>() {
+            IpAddr::V4(rng.gen())
+        } else {
+            IpAddr::V6(rng.gen())
+        }
+    }
+}
+
+#[cfg(feature = "control_flow")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "control_flow")))]
+impl<B, C> Distribution<ControlFlow<B, C>> for Standard
+where Standard: Distribution<B> + Distribution<C>
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ControlFlow<B, C> {
+        if rng.gen::<bool>() {
+            ControlFlow::Continue(rng.gen())
+        } else {
+            ControlFlow::Break(rng.gen())
+        }
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -295,6 +503,165 @@ mod tests {
         assert!(!incorrect);
     }
 
+    #[cfg(feature = "control_flow")]
+    #[test]
+    fn test_control_flow() {
+        let mut rng = crate::test::rng(486);
+        let (mut breaks, mut continues) = (0, 0);
+        for _ in 0..1000 {
+            match rng.gen::<ControlFlow<u8, u8>>() {
+                ControlFlow::Break(_) => breaks += 1,
+                ControlFlow::Continue(_) => continues += 1,
+            }
+        }
+        assert!(breaks > 400 && continues > 400);
+    }
+
+    #[test]
+    fn test_array_of_tuples() {
+        let mut rng = crate::test::rng(540);
+        let a: [(u8, f32); 3] = rng.gen();
+        assert!(a.iter().all(|&(_, f)| (0.0..1.0).contains(&f)));
+
+        // Elements are sampled sequentially, as for tuples
+        let mut rng2 = crate::test::rng(540);
+        for &(x, f) in a.iter() {
+            assert_eq!(x, rng2.gen::<u8>());
+            assert_eq!(f, rng2.gen::<f32>());
+        }
+
+        let b: [(u8, bool); 4] = rng.gen();
+        let c: [(u8, bool); 4] = rng.gen();
+        assert_ne!(b, c);
+    }
+
+    #[test]
+    fn test_bound() {
+        let mut rng = crate::test::rng(526);
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            match rng.gen::<Bound<u32>>() {
+                Bound::Included(_) => counts[0] += 1,
+                Bound::Excluded(_) => counts[1] += 1,
+                Bound::Unbounded => counts[2] += 1,
+            }
+        }
+        assert!(counts.iter().all(|&c| c > 900 && c < 1100));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut rng = crate::test::rng(493);
+        let a: Reverse<u32> = rng.gen();
+        let b: Reverse<u32> = rng.gen();
+        assert_ne!(a, b);
+        assert_eq!(a < b, a.0 > b.0);
+
+        let mut rng2 = crate::test::rng(493);
+        assert_eq!(a.0, rng2.gen::<u32>());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_ip_addr() {
+        use std::string::ToString;
+        let mut rng = crate::test::rng(535);
+        let (mut v4, mut v6) = (0, 0);
+        for _ in 0..1000 {
+            match rng.gen::<IpAddr>() {
+                IpAddr::V4(a) => {
+                    v4 += 1;
+                    assert_eq!(a.to_string().parse(), Ok(a));
+                }
+                IpAddr::V6(a) => {
+                    v6 += 1;
+                    assert_eq!(a.to_string().parse(), Ok(a));
+                }
+            }
+        }
+        // Binomial(1000, 0.5): 400 is more than 6 standard deviations out
+        assert!(v4 > 400 && v6 > 400);
+    }
+
+    #[test]
+    fn test_duration() {
+        let mut rng = crate::test::rng(516);
+        let mut max_secs = 0;
+        for _ in 0..1000 {
+            let d: Duration = rng.gen();
+            assert!(d.subsec_nanos() < 1_000_000_000);
+            max_secs = max_secs.max(d.as_secs());
+        }
+        // Seconds are uniform over all `u64` values
+        assert!(max_secs > 1 << 63);
+
+        let range = Duration::from_secs(1)..Duration::from_secs(5);
+        for _ in 0..100 {
+            let d = rng.gen_range(range.clone());
+            assert!(range.contains(&d));
+        }
+    }
+
+    #[test]
+    fn test_ascii_byte() {
+        let mut rng = crate::test::rng(503);
+        let mut seen = [false; 128];
+        for _ in 0..10_000 {
+            let b = rng.sample(AsciiByte);
+            assert_eq!(b & 0x80, 0);
+            seen[b as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+
+        #[cfg(feature = "alloc")]
+        assert!(AsciiByte.sample_string(&mut rng, 100).is_ascii());
+    }
+
+    #[test]
+    fn test_emoji() {
+        let mut rng = crate::test::rng(533);
+        let mut seen = [false; 3];
+        for _ in 0..1000 {
+            let c = rng.sample(Emoji);
+            let i = EMOJI_RANGES
+                .iter()
+                .position(|&(lo, hi)| (lo..=hi).contains(&(c as u32)))
+                .unwrap();
+            seen[i] = true;
+            assert_eq!(char::from_u32(c as u32), Some(c));
+        }
+        assert!(seen.iter().all(|&x| x));
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(Emoji.sample_string(&mut rng, 10).chars().count(), 10);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let mut rng = crate::test::rng(522);
+        let mut seen = [false; 256];
+        for _ in 0..10_000 {
+            let Wrapping(x) = rng.gen::<Wrapping<u8>>();
+            seen[x as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+
+    #[test]
+    fn test_digraph() {
+        let mut rng = crate::test::rng(521);
+        let alphabet = ['a', 'e', 'q', 'ß', '字'];
+        let digraph = Digraph::new(crate::distributions::Slice::new(&alphabet).unwrap().map(|&c| c));
+        let mut pairs = [[false; 5]; 5];
+        for _ in 0..1000 {
+            let (a, b) = rng.sample(&digraph);
+            let i = alphabet.iter().position(|&c| c == a).unwrap();
+            let j = alphabet.iter().position(|&c| c == b).unwrap();
+            pairs[i][j] = true;
+        }
+        assert!(pairs.iter().all(|row| row.iter().all(|&x| x)));
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq, D: Distribution<T>>(
@@ -0,0 +1,108 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::hash::{BuildHasher, Hash};
+use std::collections::HashMap;
+
+use crate::distributions::Distribution;
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample hash maps of random size.
+///
+/// A length `n` is sampled from the length distribution, then `n` key-value
+/// pairs are sampled from the key and value distributions. Pairs whose key is
+/// already present are skipped, thus the map holds at most `n` entries. This
+/// is useful e.g. for generating inputs for fuzzing or property testing.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rand::Rng;
+/// use rand::distributions::{RandomMap, Standard, Uniform};
+///
+/// let distr = RandomMap::new(Uniform::new_inclusive(0, 8), Standard, Standard);
+/// let map: HashMap<u32, bool> = rand::thread_rng().sample(distr);
+/// assert!(map.len() <= 8);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RandomMap<L, K, V> {
+    len_distr: L,
+    key_distr: K,
+    val_distr: V,
+}
+
+impl<L, K, V> RandomMap<L, K, V> {
+    /// Create a new `RandomMap` sampling its length from `len_distr`, and
+    /// its keys and values from `key_distr` and `val_distr`.
+    pub fn new(len_distr: L, key_distr: K, val_distr: V) -> RandomMap<L, K, V> {
+        RandomMap { len_distr, key_distr, val_distr }
+    }
+}
+
+impl<KT, VT, S, L, K, V> Distribution<HashMap<KT, VT, S>> for RandomMap<L, K, V>
+where
+    KT: Eq + Hash,
+    S: BuildHasher + Default,
+    L: Distribution<usize>,
+    K: Distribution<KT>,
+    V: Distribution<VT>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HashMap<KT, VT, S> {
+        let len = self.len_distr.sample(rng);
+        let mut map = HashMap::with_capacity_and_hasher(len, S::default());
+        for _ in 0..len {
+            let key = self.key_distr.sample(rng);
+            let val = self.val_distr.sample(rng);
+            map.entry(key).or_insert(val);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::{Standard, Uniform};
+
+    #[test]
+    fn test_random_map() {
+        let mut rng = crate::test::rng(524);
+        let distr = RandomMap::new(Uniform::new_inclusive(0, 20), Uniform::new(0u8, 10), Standard);
+        let mut sizes = [false; 11];
+        let mut keys = [false; 10];
+        for _ in 0..1000 {
+            let map: HashMap<u8, u32> = rng.sample(distr);
+            // At most 20 sampled, but only 10 distinct keys
+            assert!(map.len() <= 10);
+            sizes[map.len()] = true;
+            for &k in map.keys() {
+                keys[k as usize] = true;
+            }
+        }
+        assert!(sizes.iter().all(|&x| x));
+        assert!(keys.iter().all(|&x| x));
+
+        // Each key keeps the value sampled first
+        let distr = RandomMap::new(Uniform::new_inclusive(50, 50), Uniform::new(0u8, 20), Standard);
+        let mut rng1 = crate::test::rng(525);
+        let mut rng2 = crate::test::rng(525);
+        let map: HashMap<u8, u64> = rng1.sample(distr);
+        assert_eq!(rng2.sample(Uniform::new_inclusive(50usize, 50)), 50);
+        let mut expected = HashMap::new();
+        for _ in 0..50 {
+            let k = rng2.sample(Uniform::new(0u8, 20));
+            let v: u64 = rng2.gen();
+            expected.entry(k).or_insert(v);
+        }
+        assert_eq!(map, expected);
+    }
+}
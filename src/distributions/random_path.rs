@@ -0,0 +1,122 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::{is_separator, PathBuf};
+use std::string::String;
+
+use crate::distributions::Distribution;
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample relative paths of random depth.
+///
+/// The depth (number of components) is sampled from the depth distribution,
+/// then each component name from the name distribution. Path separators and
+/// NUL characters are removed from sampled names, and names which are then
+/// empty, `.` or `..` are resampled; use
+/// [`RandomPath::allow_dot_components`] to keep `.` and `..`. This is useful
+/// e.g. for fuzzing path-handling code.
+///
+/// Since names may be resampled, the name distribution should produce valid
+/// names with non-negligible probability.
+///
+/// # Example
+///
+/// ```
+/// use std::path::PathBuf;
+/// use rand::Rng;
+/// use rand::distributions::{Distribution, RandomPath, Standard, Uniform};
+///
+/// let names = Standard.map(|x: u16| format!("f{:x}", x));
+/// let distr = RandomPath::new(Uniform::new_inclusive(1, 4), names);
+/// let path: PathBuf = rand::thread_rng().sample(distr);
+/// assert!((1..=4).contains(&path.components().count()));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RandomPath<L, N> {
+    depth_distr: L,
+    name_distr: N,
+    allow_dots: bool,
+}
+
+impl<L, N> RandomPath<L, N> {
+    /// Create a new `RandomPath` sampling its depth from `depth_distr` and
+    /// component names from `name_distr`.
+    pub fn new(depth_distr: L, name_distr: N) -> RandomPath<L, N> {
+        RandomPath { depth_distr, name_distr, allow_dots: false }
+    }
+
+    /// Set whether `.` and `..` may be used as component names.
+    ///
+    /// Note that `.` components are normalized away by
+    /// [`Path::components`](std::path::Path::components), except at the
+    /// start.
+    pub fn allow_dot_components(mut self, allow: bool) -> RandomPath<L, N> {
+        self.allow_dots = allow;
+        self
+    }
+}
+
+impl<L, N> Distribution<PathBuf> for RandomPath<L, N>
+where
+    L: Distribution<usize>,
+    N: Distribution<String>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PathBuf {
+        let depth = self.depth_distr.sample(rng);
+        let mut path = PathBuf::new();
+        for _ in 0..depth {
+            loop {
+                let mut name = self.name_distr.sample(rng);
+                name.retain(|c| !is_separator(c) && c != '\0');
+                let is_dots = name == "." || name == "..";
+                if !name.is_empty() && (self.allow_dots || !is_dots) {
+                    path.push(name);
+                    break;
+                }
+            }
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distributions::{Slice, Uniform};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_random_path() {
+        let mut rng = crate::test::rng(528);
+        let candidates = ["", ".", "..", "a/b", "c\0", "d\\e", "file.txt"];
+        let names = Slice::new(&candidates).unwrap().map(|s| String::from(*s));
+        let distr = RandomPath::new(Uniform::new_inclusive(0, 6), names);
+
+        let mut depths = [false; 7];
+        for _ in 0..500 {
+            let path: PathBuf = rng.sample(&distr);
+            let s = path.to_str().unwrap();
+            assert!(!s.contains('\0'));
+            let components: Vec<_> = path.iter().map(|c| c.to_str().unwrap()).collect();
+            assert!(components.iter().all(|c| !c.contains('/') && *c != "." && *c != ".."));
+            assert_eq!(path.components().count(), components.len());
+            depths[components.len()] = true;
+        }
+        assert!(depths.iter().all(|&x| x));
+
+        // With dot components allowed, `..` may occur
+        let distr = distr.allow_dot_components(true);
+        assert!((0..100).any(|_| {
+            let path: PathBuf = rng.sample(&distr);
+            path.iter().any(|c| c == "..")
+        }));
+    }
+}
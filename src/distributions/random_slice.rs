@@ -0,0 +1,98 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::Range;
+
+use crate::distributions::{Distribution, Uniform};
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample sub-slice ranges of a slice of length `len`.
+///
+/// Samples are ranges `start..end` with `0 <= start <= end <= len`, thus
+/// valid for indexing a slice of length `len`. Each of the
+/// `(len + 1) * (len + 2) / 2` such ranges (including the empty ranges) is
+/// equally likely. This is useful e.g. for fuzzing slicing code.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::RandomSlice;
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let range = rand::thread_rng().sample(RandomSlice::new(data.len()));
+/// println!("{:?}", &data[range]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RandomSlice {
+    first: Uniform<usize>,
+    second: Uniform<usize>,
+}
+
+impl RandomSlice {
+    /// Create a new `RandomSlice` sampling ranges within `0..len`.
+    ///
+    /// # Panics
+    ///
+    /// If `len > usize::MAX - 2`.
+    pub fn new(len: usize) -> RandomSlice {
+        assert!(len <= core::usize::MAX - 2, "RandomSlice::new: len too large");
+        RandomSlice {
+            first: Uniform::new(0, len + 2),
+            second: Uniform::new(0, len + 1),
+        }
+    }
+}
+
+impl Distribution<Range<usize>> for RandomSlice {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Range<usize> {
+        // Ranges correspond to pairs `a < b` of distinct values in
+        // `0..len + 2` via `a..b - 1`; sample such a pair uniformly.
+        let a = self.first.sample(rng);
+        let mut b = self.second.sample(rng);
+        if b >= a {
+            b += 1;
+        }
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        a..b - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_random_slice() {
+        let mut rng = crate::test::rng(531);
+        let buf = [0u8; 6];
+        let distr = RandomSlice::new(buf.len());
+        // There are 28 ranges, each with probability 1/28
+        let mut counts = [[0; 7]; 7];
+        for _ in 0..28_000 {
+            let range = rng.sample(distr);
+            assert!(range.start <= range.end && range.end <= buf.len());
+            assert_eq!(buf[range.clone()].len(), range.end - range.start);
+            counts[range.start][range.end] += 1;
+        }
+        for (start, row) in counts.iter().enumerate() {
+            for (end, &c) in row.iter().enumerate() {
+                if start <= end {
+                    assert!(c > 800 && c < 1200, "{}..{}: {}", start, end, c);
+                } else {
+                    assert_eq!(c, 0);
+                }
+            }
+        }
+
+        assert_eq!(rng.sample(RandomSlice::new(0)), 0..0);
+    }
+}
@@ -7,6 +7,10 @@
 // except according to those terms.
 
 use crate::distributions::{Distribution, Uniform};
+#[cfg(feature = "alloc")]
+use crate::distributions::DistString;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 
 /// A distribution to sample items uniformly from a slice.
 ///
@@ -99,6 +103,60 @@ impl<'a, T> Distribution<&'a T> for Slice<'a, T> {
     }
 }
 
+/// A distribution to sample strings from a palette of graphemes.
+///
+/// Graphemes (user-perceived characters) may consist of multiple `char`s,
+/// thus palette entries are string slices and samples are `String`s. Each
+/// sample is one palette entry, chosen uniformly; [`DistString`] may be used
+/// to sample strings of multiple graphemes.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::{DistString, GraphemePalette};
+///
+/// let palette = ["😀", "🎲", "★", "e\u{301}"];
+/// let distr = GraphemePalette::new(&palette).unwrap();
+/// let mut rng = rand::thread_rng();
+///
+/// let g: String = rng.sample(distr);
+/// assert!(palette.contains(&g.as_str()));
+/// println!("{}", distr.sample_string(&mut rng, 8));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy)]
+pub struct GraphemePalette<'a> {
+    slice: Slice<'a, &'a str>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> GraphemePalette<'a> {
+    /// Create a new `GraphemePalette` which samples uniformly from `palette`.
+    /// Returns `Err` if the palette is empty.
+    pub fn new(palette: &'a [&'a str]) -> Result<Self, EmptySlice> {
+        Ok(GraphemePalette { slice: Slice::new(palette)? })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Distribution<String> for GraphemePalette<'a> {
+    fn sample<R: crate::Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.slice.sample(rng).to_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DistString for GraphemePalette<'a> {
+    /// Append `len` random graphemes to `string`
+    fn append_string<R: crate::Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
+        for _ in 0..len {
+            string.push_str(self.slice.sample(rng));
+        }
+    }
+}
+
 /// Error type indicating that a [`Slice`] distribution was improperly
 /// constructed with an empty slice.
 #[derive(Debug, Clone, Copy)]
@@ -115,3 +173,34 @@ impl core::fmt::Display for EmptySlice {
 
 #[cfg(feature = "std")]
 impl std::error::Error for EmptySlice {}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_grapheme_palette() {
+        use super::*;
+        use crate::Rng;
+
+        let palette = ["😀", "🎲", "★", "e\u{301}"];
+        let distr = GraphemePalette::new(&palette).unwrap();
+        let mut rng = crate::test::rng(498);
+        let mut seen = [false; 4];
+        for _ in 0..100 {
+            let g: String = rng.sample(distr);
+            let pos = palette.iter().position(|&p| p == g).unwrap();
+            seen[pos] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+
+        let s = distr.sample_string(&mut rng, 10);
+        let mut rest = s.as_str();
+        for _ in 0..10 {
+            let p = palette.iter().find(|p| rest.starts_with(*p)).unwrap();
+            rest = &rest[p.len()..];
+        }
+        assert!(rest.is_empty());
+
+        assert!(GraphemePalette::new(&[]).is_err());
+    }
+}
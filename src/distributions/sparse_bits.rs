@@ -0,0 +1,103 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::RangeInclusive;
+
+use crate::distributions::{Distribution, Uniform};
+use crate::Rng;
+#[cfg(feature = "serde1")]
+use serde::{Serialize, Deserialize};
+
+/// A distribution to sample `u64` bitmasks with a random number of set bits.
+///
+/// [`SparseBits::new`] constructs a distribution over masks of the low
+/// `total_bits` bits. The number of set bits is sampled uniformly from
+/// `set_range`, then the positions of these bits uniformly among all
+/// `total_bits` positions (using Floyd's combination algorithm). This is
+/// useful e.g. for fuzzing sparse feature flags.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::SparseBits;
+///
+/// let mask: u64 = rand::thread_rng().sample(SparseBits::new(16, 1..=3));
+/// assert!((1..=3).contains(&mask.count_ones()));
+/// assert!(mask < 1 << 16);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SparseBits {
+    total_bits: u32,
+    count: Uniform<u32>,
+}
+
+impl SparseBits {
+    /// Create a new `SparseBits` setting between `set_range.start()` and
+    /// `set_range.end()` (inclusive) of the low `total_bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// If `total_bits > 64`, `set_range` is empty or
+    /// `set_range.end() > total_bits`.
+    pub fn new(total_bits: u32, set_range: RangeInclusive<u32>) -> SparseBits {
+        assert!(total_bits <= 64, "SparseBits::new: total_bits > 64");
+        let (low, high) = (*set_range.start(), *set_range.end());
+        assert!(low <= high, "SparseBits::new: empty set_range");
+        assert!(high <= total_bits, "SparseBits::new: set_range exceeds total_bits");
+        SparseBits {
+            total_bits,
+            count: Uniform::new_inclusive(low, high),
+        }
+    }
+}
+
+impl Distribution<u64> for SparseBits {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let count = self.count.sample(rng);
+        let mut mask = 0u64;
+        for j in self.total_bits - count..self.total_bits {
+            let t = rng.gen_range(0..=j);
+            mask |= if mask & (1 << t) == 0 { 1 << t } else { 1 << j };
+        }
+        mask
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sparse_bits() {
+        let mut rng = crate::test::rng(529);
+        let distr = SparseBits::new(20, 2..=5);
+        let mut counts = [0; 6];
+        let mut positions = 0;
+        for _ in 0..1000 {
+            let mask = rng.sample(distr);
+            assert!(mask < 1 << 20);
+            let n = mask.count_ones();
+            assert!((2..=5).contains(&n));
+            counts[n as usize] += 1;
+            positions |= mask;
+        }
+        assert!(counts[2..].iter().all(|&c| c > 200));
+        assert_eq!(positions, (1 << 20) - 1);
+
+        assert_eq!(rng.sample(SparseBits::new(64, 64..=64)), core::u64::MAX);
+        assert_eq!(rng.sample(SparseBits::new(0, 0..=0)), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sparse_bits_invalid() {
+        SparseBits::new(8, 3..=9);
+    }
+}
@@ -15,8 +15,9 @@
 //! [`Uniform`].
 //!
 //! This distribution is provided with support for several primitive types
-//! (all integer and floating-point types) as well as [`std::time::Duration`],
-//! and supports extension to user-defined types via a type-specific *back-end*
+//! (all integer and floating-point types) as well as [`std::time::Duration`]
+//! and [`std::time::SystemTime`] (the latter requires `std`), and supports
+//! extension to user-defined types via a type-specific *back-end*
 //! implementation.
 //!
 //! The types [`UniformInt`], [`UniformFloat`] and [`UniformDuration`] are the
@@ -104,8 +105,9 @@
 //! [`SampleBorrow::borrow`]: crate::distributions::uniform::SampleBorrow::borrow
 
 #[cfg(not(feature = "std"))] use core::time::Duration;
-#[cfg(feature = "std")] use std::time::Duration;
-use core::ops::{Range, RangeInclusive};
+#[cfg(feature = "std")] use std::time::{Duration, SystemTime};
+use core::fmt;
+use core::ops::{Range, RangeFrom, RangeInclusive, RangeTo};
 
 use crate::distributions::float::IntoFloat;
 use crate::distributions::utils::{BoolAsSIMD, FloatAsSIMD, FloatSIMDUtils, WideningMultiply};
@@ -279,6 +281,25 @@ pub trait UniformSampler: Sized {
         uniform.sample(rng)
     }
 
+    /// Sample a single value uniformly from a range with inclusive lower bound
+    /// and exclusive upper bound `[low, high)`, also returning the number of
+    /// candidates rejected before acceptance.
+    ///
+    /// This is intended for analysing the efficiency of rejection sampling.
+    /// The value is the same as that of [`sample_single`]. The default
+    /// implementation reports 0 rejections; it is overridden for integer
+    /// types, which use rejection sampling, and for floating-point types,
+    /// which retry when the result rounds up to `high`.
+    ///
+    /// [`sample_single`]: UniformSampler::sample_single
+    fn sample_single_counted<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R)
+        -> (Self::X, u32)
+        where B1: SampleBorrow<Self::X> + Sized,
+              B2: SampleBorrow<Self::X> + Sized
+    {
+        (Self::sample_single(low, high, rng), 0)
+    }
+
     /// Sample a single value uniformly from a range with inclusive lower bound
     /// and inclusive upper bound `[low, high]`.
     ///
@@ -350,6 +371,19 @@ pub trait SampleRange<T> {
     fn is_empty(&self) -> bool;
 }
 
+/// Error type returned from [`Rng::try_gen_range`] for an empty range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeError;
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot sample empty range")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for RangeError {}
+
 impl<T: SampleUniform + PartialOrd> SampleRange<T> for Range<T> {
     #[inline]
     fn sample_single<R: RngCore + ?Sized>(self, rng: &mut R) -> T {
@@ -483,6 +517,17 @@ macro_rules! uniform_int_impl {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
                 let range = self.range as $unsigned as $u_large;
+                if range == 1 {
+                    // Only one possible value; don't consume random data.
+                    return self.low;
+                }
+                // 128-bit types sample narrow ranges from single words.
+                if ::core::mem::size_of::<$u_large>() > 8
+                    && range > 0
+                    && range as u128 <= ::core::u64::MAX as u128
+                {
+                    return self.low.wrapping_add(sample_below_u64(range as u64, rng).0 as $ty);
+                }
                 if range > 0 {
                     let unsigned_max = ::core::$u_large::MAX;
                     let zone = unsigned_max - (self.z as $unsigned as $u_large);
@@ -520,11 +565,44 @@ macro_rules! uniform_int_impl {
                 let low = *low_b.borrow();
                 let high = *high_b.borrow();
                 assert!(low <= high, "UniformSampler::sample_single_inclusive: low > high");
+                Self::sample_single_inclusive_counted(low, high, rng).0
+            }
+
+            #[inline]
+            fn sample_single_counted<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R)
+                -> (Self::X, u32)
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = *low_b.borrow();
+                let high = *high_b.borrow();
+                assert!(low < high, "UniformSampler::sample_single_counted: low >= high");
+                Self::sample_single_inclusive_counted(low, high - 1, rng)
+            }
+        }
+
+        impl UniformInt<$ty> {
+            // Sample from `[low, high]`, also returning the number of rejected
+            // candidates.
+            #[inline]
+            fn sample_single_inclusive_counted<R: Rng + ?Sized>(low: $ty, high: $ty, rng: &mut R)
+                -> ($ty, u32)
+            {
                 let range = high.wrapping_sub(low).wrapping_add(1) as $unsigned as $u_large;
                 // If the above resulted in wrap-around to 0, the range is $ty::MIN..=$ty::MAX,
                 // and any integer will do.
                 if range == 0 {
-                    return rng.gen();
+                    return (rng.gen(), 0);
+                }
+                if range == 1 {
+                    return (low, 0);
+                }
+                if ::core::mem::size_of::<$u_large>() > 8
+                    && range as u128 <= ::core::u64::MAX as u128
+                {
+                    let (x, rejected) = sample_below_u64(range as u64, rng);
+                    return (low.wrapping_add(x as $ty), rejected);
                 }
 
                 let zone = if ::core::$unsigned::MAX <= ::core::u16::MAX as $unsigned {
@@ -540,18 +618,40 @@ macro_rules! uniform_int_impl {
                     (range << range.leading_zeros()).wrapping_sub(1)
                 };
 
+                let mut rejected = 0;
                 loop {
                     let v: $u_large = rng.gen();
                     let (hi, lo) = v.wmul(range);
                     if lo <= zone {
-                        return low.wrapping_add(hi as $ty);
+                        return (low.wrapping_add(hi as $ty), rejected);
                     }
+                    rejected += 1;
                 }
             }
         }
     };
 }
 
+/// Sample uniformly from `0..range`, where `range > 0`, using `u64` samples,
+/// also returning the number of rejected candidates.
+///
+/// 128-bit types use this for ranges fitting in 64 bits, which thus consume
+/// one word per attempt instead of two.
+#[inline]
+fn sample_below_u64<R: Rng + ?Sized>(range: u64, rng: &mut R) -> (u64, u32) {
+    // conservative but fast approximation, as in `sample_single_inclusive`
+    let zone = (range << range.leading_zeros()).wrapping_sub(1);
+    let mut rejected = 0;
+    loop {
+        let v: u64 = rng.gen();
+        let (hi, lo) = v.wmul(range);
+        if lo <= zone {
+            return (hi, rejected);
+        }
+        rejected += 1;
+    }
+}
+
 uniform_int_impl! { i8, u8, u32 }
 uniform_int_impl! { i16, u16, u32 }
 uniform_int_impl! { i32, u32, u32 }
@@ -567,6 +667,52 @@ uniform_int_impl! { usize, usize, usize }
 #[cfg(not(target_os = "emscripten"))]
 uniform_int_impl! { u128, u128, u128 }
 
+// Half-open ranges of integers use the type's minimum or maximum as the
+// missing bound: `..high` samples `[MIN, high)` and `low..` samples
+// `[low, MAX]`, matching the values the range contains.
+macro_rules! sample_range_open_impl {
+    ($ty:ident) => {
+        impl SampleRange<$ty> for RangeTo<$ty> {
+            #[inline]
+            fn sample_single<R: RngCore + ?Sized>(self, rng: &mut R) -> $ty {
+                (core::$ty::MIN..self.end).sample_single(rng)
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                self.end == core::$ty::MIN
+            }
+        }
+
+        impl SampleRange<$ty> for RangeFrom<$ty> {
+            #[inline]
+            fn sample_single<R: RngCore + ?Sized>(self, rng: &mut R) -> $ty {
+                (self.start..=core::$ty::MAX).sample_single(rng)
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                false
+            }
+        }
+    };
+}
+
+sample_range_open_impl! { i8 }
+sample_range_open_impl! { i16 }
+sample_range_open_impl! { i32 }
+sample_range_open_impl! { i64 }
+#[cfg(not(target_os = "emscripten"))]
+sample_range_open_impl! { i128 }
+sample_range_open_impl! { isize }
+sample_range_open_impl! { u8 }
+sample_range_open_impl! { u16 }
+sample_range_open_impl! { u32 }
+sample_range_open_impl! { u64 }
+sample_range_open_impl! { usize }
+#[cfg(not(target_os = "emscripten"))]
+sample_range_open_impl! { u128 }
+
 #[cfg(feature = "simd_support")]
 macro_rules! uniform_simd_int_impl {
     ($ty:ident, $unsigned:ident, $u_scalar:ident) => {
@@ -913,6 +1059,16 @@ macro_rules! uniform_float_impl {
 
             #[inline]
             fn sample_single<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R) -> Self::X
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self::sample_single_counted(low_b, high_b, rng).0
+            }
+
+            #[inline]
+            fn sample_single_counted<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R)
+                -> (Self::X, u32)
             where
                 B1: SampleBorrow<Self::X> + Sized,
                 B2: SampleBorrow<Self::X> + Sized,
@@ -934,6 +1090,7 @@ macro_rules! uniform_float_impl {
                 let mut scale = high - low;
                 assert!(scale.all_finite(), "UniformSampler::sample_single: range overflow");
 
+                let mut rejected = 0;
                 loop {
                     // Generate a value in the range [1, 2)
                     let value1_2 =
@@ -949,8 +1106,9 @@ macro_rules! uniform_float_impl {
 
                     debug_assert!(low.all_le(res) || !scale.all_finite());
                     if res.all_lt(high) {
-                        return res;
+                        return (res, rejected);
                     }
+                    rejected += 1;
 
                     // This handles a number of edge cases.
                     // * `low` or `high` is NaN. In this case `scale` and
@@ -1145,6 +1303,72 @@ impl UniformSampler for UniformDuration {
     }
 }
 
+/// The back-end implementing [`UniformSampler`] for `SystemTime`.
+///
+/// A `SystemTime` is sampled by sampling the [`Duration`] elapsed since `low`
+/// via [`UniformDuration`], thus the same precision (one nanosecond) applies.
+///
+/// Unless you are implementing [`UniformSampler`] for your own types, this type
+/// should not be used directly, use [`Uniform`] instead.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct UniformSystemTime {
+    low: SystemTime,
+    offset: UniformDuration,
+}
+
+#[cfg(feature = "std")]
+impl SampleUniform for SystemTime {
+    type Sampler = UniformSystemTime;
+}
+
+#[cfg(feature = "std")]
+impl UniformSampler for UniformSystemTime {
+    type X = SystemTime;
+
+    #[inline]
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        let span = high.duration_since(low).unwrap();
+        UniformSystemTime {
+            low,
+            offset: UniformDuration::new(Duration::new(0, 0), span),
+        }
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+        assert!(
+            low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+        let span = high.duration_since(low).unwrap();
+        UniformSystemTime {
+            low,
+            offset: UniformDuration::new_inclusive(Duration::new(0, 0), span),
+        }
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SystemTime {
+        self.low + self.offset.sample(rng)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1200,6 +1424,30 @@ mod tests {
         assert_eq!(unit_box.0.scale, de_unit_box.0.scale);
     }
 
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn test_uniform_serialization_sampling() {
+        let mut rng = crate::test::rng(538);
+        let distr = Uniform::new(10u32, 20);
+        let de_distr: Uniform<u32> =
+            bincode::deserialize(&bincode::serialize(&distr).unwrap()).unwrap();
+        let mut rng2 = crate::test::rng(538);
+        for _ in 0..100 {
+            let x = de_distr.sample(&mut rng);
+            assert!((10..20).contains(&x));
+            assert_eq!(x, distr.sample(&mut rng2));
+        }
+
+        let distr = Uniform::new_inclusive(-2.5f64, 7.5);
+        let de_distr: Uniform<f64> =
+            bincode::deserialize(&bincode::serialize(&distr).unwrap()).unwrap();
+        for _ in 0..100 {
+            let x = de_distr.sample(&mut rng);
+            assert!((-2.5..=7.5).contains(&x));
+            assert_eq!(x, distr.sample(&mut rng2));
+        }
+    }
+
     #[should_panic]
     #[test]
     fn test_uniform_bad_limits_equal_int() {
@@ -1215,6 +1463,80 @@ mod tests {
         }
     }
 
+    // Counts the words drawn from the inner RNG
+    struct CountingRng(usize, StepRng);
+    impl RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 += 1;
+            self.1.next_u32()
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.1.next_u64()
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.0 += 1;
+            self.1.fill_bytes(dest)
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), crate::Error> {
+            self.0 += 1;
+            self.1.try_fill_bytes(dest)
+        }
+    }
+
+    #[test]
+    fn test_uniform_width_one_int() {
+        let mut rng = CountingRng(0, StepRng::new(0, 1));
+        let dist = Uniform::new(-3i64, -2);
+        for _ in 0..10 {
+            assert_eq!(rng.sample(dist), -3);
+            assert_eq!(rng.gen_range(7u8..8), 7);
+            assert_eq!(rng.gen_range(5usize..=5), 5);
+            assert_eq!(rng.sample(Uniform::new_inclusive(9u32, 9)), 9);
+        }
+        assert_eq!(rng.0, 0);
+
+        rng.gen_range(7u8..9);
+        assert_eq!(rng.0, 1);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "emscripten"))]
+    fn test_uniform_u128_narrow() {
+        let mut rng = CountingRng(0, StepRng::new(0, 0x9E37_79B9_7F4A_7C15));
+        // A range fitting in 64 bits draws one word per attempt
+        let narrow = Uniform::new(5u128, 1000);
+        for _ in 0..100 {
+            let x = rng.sample(narrow);
+            assert!((5..1000).contains(&x));
+        }
+        assert!(rng.0 < 110, "{} words drawn", rng.0);
+
+        rng.0 = 0;
+        for _ in 0..100 {
+            let x = rng.gen_range(-3i128..core::u64::MAX as i128 - 3);
+            assert!((-3..core::u64::MAX as i128 - 3).contains(&x));
+        }
+        assert!(rng.0 < 110, "{} words drawn", rng.0);
+
+        // Wider ranges draw two words per attempt
+        rng.0 = 0;
+        let wide = Uniform::new_inclusive(0u128, 1 << 100);
+        for _ in 0..100 {
+            assert!(rng.sample(wide) <= 1 << 100);
+        }
+        assert!(rng.0 >= 200);
+
+        let mut rng = crate::test::rng(506);
+        let mut hist = [0usize; 10];
+        for _ in 0..10_000 {
+            hist[rng.gen_range(10u128..20) as usize - 10] += 1;
+        }
+        for &count in hist.iter() {
+            assert!(count > 900 && count < 1100, "{:?}", hist);
+        }
+    }
+
     #[should_panic]
     #[test]
     fn test_uniform_bad_limits_flipped_int() {
@@ -1330,6 +1652,27 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_char_full_range() {
+        let mut rng = crate::test::rng(892);
+        for _ in 0..100 {
+            assert_eq!(rng.gen_range('a'..'b'), 'a');
+        }
+        let full = Uniform::new_inclusive('\0', core::char::MAX);
+        let mut above_surrogates = 0;
+        for _ in 0..1000 {
+            // Any invalid scalar value would be caught by the round trip
+            let c = rng.sample(full);
+            assert_eq!(core::char::from_u32(c as u32), Some(c));
+            above_surrogates += (c as u32 > 0xDFFF) as u32;
+            let c = rng.gen_range('\0'..=core::char::MAX);
+            assert_eq!(core::char::from_u32(c as u32), Some(c));
+        }
+        // Most scalar values lie above the surrogate gap
+        assert!(above_surrogates > 900);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_floats() {
@@ -1527,6 +1870,29 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_times() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let mut rng = crate::test::rng(254);
+
+        let t0 = UNIX_EPOCH + Duration::new(1_600_000_000, 123);
+        let v = &[
+            (t0, t0 + Duration::new(0, 1)),
+            (t0, t0 + Duration::new(3600, 0)),
+            (UNIX_EPOCH - Duration::new(100, 0), t0),
+        ];
+        for &(low, high) in v.iter() {
+            for _ in 0..1000 {
+                let t: SystemTime = rng.gen_range(low..high);
+                assert!(low <= t && t < high);
+                let t: SystemTime = rng.gen_range(low..=high);
+                assert!(low <= t && t <= high);
+            }
+        }
+    }
+
     #[test]
     fn test_custom_uniform() {
         use crate::distributions::uniform::{
@@ -7,9 +7,46 @@
 // except according to those terms.
 
 //! Math helper functions
+//!
+//! Most of this module is internal; [`rejection_sample`] is exposed as a
+//! building block for custom distributions.
 
 #[cfg(feature = "simd_support")] use packed_simd::*;
-
+use crate::Rng;
+
+/// Sample by rejection: repeatedly draw from `propose` until `accept` holds.
+///
+/// At most `max_iters` proposals are drawn; if none of them is accepted,
+/// `None` is returned. This bounds the running time where the acceptance
+/// probability may be very small (or zero).
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distributions::utils::rejection_sample;
+///
+/// let mut rng = rand::thread_rng();
+/// let even = rejection_sample(&mut rng, |rng| rng.gen_range(0..100u32),
+///     |x| x % 2 == 0, 1000);
+/// assert!(even.map_or(true, |x| x % 2 == 0));
+/// ```
+pub fn rejection_sample<T, R, P, S>(
+    rng: &mut R, mut propose: P, mut accept: S, max_iters: usize,
+) -> Option<T>
+where
+    R: Rng + ?Sized,
+    P: FnMut(&mut R) -> T,
+    S: FnMut(&T) -> bool,
+{
+    for _ in 0..max_iters {
+        let x = propose(rng);
+        if accept(&x) {
+            return Some(x);
+        }
+    }
+    None
+}
 
 pub(crate) trait WideningMultiply<RHS = Self> {
     type Output;
@@ -429,3 +466,27 @@ macro_rules! simd_impl {
 #[cfg(feature="simd_support")] simd_impl! { f64x2, f64, m64x2, u64x2 }
 #[cfg(feature="simd_support")] simd_impl! { f64x4, f64, m64x4, u64x4 }
 #[cfg(feature="simd_support")] simd_impl! { f64x8, f64, m64x8, u64x8 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rejection_sample() {
+        let mut rng = crate::test::rng(481);
+        for _ in 0..100 {
+            let x = rejection_sample(&mut rng, |rng| rng.gen_range(0..1000u32),
+                |x| x % 2 == 0, 1000);
+            assert_eq!(x.map(|x| x % 2), Some(0));
+        }
+
+        let mut count = 0;
+        let x = rejection_sample(&mut rng, |rng| { count += 1; rng.gen::<u32>() },
+            |_| false, 50);
+        assert_eq!(x, None);
+        assert_eq!(count, 50);
+
+        let x = rejection_sample(&mut rng, |rng| rng.gen::<u32>(), |_| true, 0);
+        assert_eq!(x, None);
+    }
+}
@@ -218,6 +218,26 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
 
         Ok(())
     }
+
+    /// Sample `n` indices, returned in order of sampling.
+    ///
+    /// This reserves the output once up front and is equivalent to (but
+    /// faster than) collecting `n` calls to [`Distribution::sample`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distributions::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new(&[2, 1, 1]).unwrap();
+    /// let indices = dist.sample_indices(&mut rand::thread_rng(), 100);
+    /// assert!(indices.iter().all(|&i| i < 3));
+    /// ```
+    pub fn sample_indices<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(n);
+        indices.extend((0..n).map(|_| self.sample(rng)));
+        indices
+    }
 }
 
 impl<X> Distribution<usize> for WeightedIndex<X>
@@ -259,6 +279,30 @@ mod test {
         assert_eq!(de_weighted_index.total_weight, weighted_index.total_weight);
     }
 
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_weightedindex_serde1_sampling() {
+        let weights = [1.5f64, 0.0, 3.0, 5.5];
+        let weighted_index = WeightedIndex::new(&weights).unwrap();
+        let de_weighted_index: WeightedIndex<f64> =
+            bincode::deserialize(&bincode::serialize(&weighted_index).unwrap()).unwrap();
+        assert_eq!(de_weighted_index.total_weight, 10.0);
+
+        // Sampling is identical, thus so are the frequencies
+        let mut rng = crate::test::rng(540);
+        let mut rng2 = crate::test::rng(540);
+        const N: usize = 10_000;
+        let mut counts = [0usize; 4];
+        for _ in 0..N {
+            let i = de_weighted_index.sample(&mut rng);
+            assert_eq!(i, weighted_index.sample(&mut rng2));
+            counts[i] += 1;
+        }
+        for (&c, &w) in counts.iter().zip(weights.iter()) {
+            assert!((c as f64 / N as f64 - w / 10.).abs() < 0.02);
+        }
+    }
+
     #[test]
     fn test_accepting_nan(){
         assert_eq!(
@@ -284,6 +328,19 @@ mod test {
     }
 
 
+    #[test]
+    fn test_sample_indices() {
+        let distr = WeightedIndex::new(&[1.0, 0.0, 2.5, 3.0]).unwrap();
+        let mut r1 = crate::test::rng(523);
+        let mut r2 = crate::test::rng(523);
+        let indices = distr.sample_indices(&mut r1, 100);
+        assert_eq!(indices.len(), 100);
+        for i in indices {
+            assert_eq!(i, distr.sample(&mut r2));
+        }
+        assert!(distr.sample_indices(&mut r1, 0).is_empty());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_weightedindex() {
@@ -319,6 +376,14 @@ mod test {
         }
         verify(chosen);
 
+        // WeightedIndex batch sampling
+        chosen = [0i32; 14];
+        let distr = WeightedIndex::new(&weights[..]).unwrap();
+        for i in distr.sample_indices(&mut r, N_REPS as usize) {
+            chosen[i] += 1;
+        }
+        verify(chosen);
+
         // WeightedIndex from iterator
         chosen = [0i32; 14];
         let distr = WeightedIndex::new(weights.iter()).unwrap();
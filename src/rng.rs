@@ -10,10 +10,15 @@
 //! [`Rng`] trait
 
 use rand_core::{Error, RngCore};
-use crate::distributions::uniform::{SampleRange, SampleUniform};
+use crate::distributions::uniform::{RangeError, SampleRange, SampleUniform, UniformSampler};
 use crate::distributions::{self, Distribution, Standard};
 use core::num::Wrapping;
+use core::ops::{RangeFrom, RangeTo};
+#[cfg(feature = "std")] use core::ops::Range;
 use core::{mem, slice};
+#[cfg(feature = "alloc")] use alloc::string::String;
+#[cfg(feature = "atomic")]
+use core::sync::atomic::{AtomicU32, AtomicU64};
 
 /// An automatically-implemented extension trait on [`RngCore`] providing high-level
 /// generic methods for sampling values and other convenience methods.
@@ -135,6 +140,149 @@ pub trait Rng: RngCore {
         range.sample_single(self)
     }
 
+    /// Generate a random value in the given range, returning an error if the
+    /// range is empty.
+    ///
+    /// This is a non-panicking variant of [`Rng::gen_range`], e.g. for ranges
+    /// derived from untrusted input. Note that sampling a float range with
+    /// non-finite bounds or width still panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// assert!(rng.try_gen_range(2..5).is_ok());
+    /// assert!(rng.try_gen_range(5..2).is_err());
+    /// ```
+    fn try_gen_range<T, R>(&mut self, range: R) -> Result<T, RangeError>
+    where
+        T: SampleUniform,
+        R: SampleRange<T>
+    {
+        if range.is_empty() {
+            return Err(RangeError);
+        }
+        Ok(range.sample_single(self))
+    }
+
+    /// Generate a random value in the range `..high`, i.e. at least the
+    /// minimum value of `T` and strictly less than `high`.
+    ///
+    /// This is equivalent to `self.gen_range(..high)`; like `..high`, the
+    /// upper bound is exclusive. Supported for all primitive integer types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `high` is the minimum value of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let x: i8 = thread_rng().gen_range_from_start(-100);
+    /// assert!(x < -100);
+    /// ```
+    fn gen_range_from_start<T>(&mut self, high: T) -> T
+    where
+        T: SampleUniform,
+        RangeTo<T>: SampleRange<T>
+    {
+        self.gen_range(..high)
+    }
+
+    /// Generate a random value in the range `low..`, i.e. at least `low` and
+    /// at most the maximum value of `T`.
+    ///
+    /// This is equivalent to `self.gen_range(low..)`; like `low..`, the range
+    /// includes the maximum value of `T`, and is never empty. Supported for
+    /// all primitive integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let x: u8 = thread_rng().gen_range_to_end(250);
+    /// assert!(x >= 250);
+    /// ```
+    fn gen_range_to_end<T>(&mut self, low: T) -> T
+    where
+        T: SampleUniform,
+        RangeFrom<T>: SampleRange<T>
+    {
+        self.gen_range(low..)
+    }
+
+    /// Generate a random value in the range `[low, high)`, also returning the
+    /// number of candidates rejected before acceptance.
+    ///
+    /// The value is the same as that of `self.gen_range(low..high)`. This is
+    /// intended for studying the efficiency of rejection sampling; see
+    /// [`UniformSampler::sample_single_counted`]. For 8- and 16-bit integers,
+    /// which use an exact rejection zone, ranges whose size is a power of two
+    /// never reject; wider integer types use a cheaper, conservative zone,
+    /// rejecting up to half of all candidates. Floating-point types only
+    /// reject results which round up to `high`, which is rare unless the
+    /// range spans few representable values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let (x, rejected) = thread_rng().gen_range_counted(0u32, 1000);
+    /// println!("{} after {} rejections", x, rejected);
+    /// ```
+    ///
+    /// [`UniformSampler::sample_single_counted`]: distributions::uniform::UniformSampler::sample_single_counted
+    fn gen_range_counted<T: SampleUniform>(&mut self, low: T, high: T) -> (T, u32) {
+        T::Sampler::sample_single_counted(low, high, self)
+    }
+
+    /// Generate a random index in the given range, biased towards its low end.
+    ///
+    /// The result is `low + floor((high - low) * u^exponent)` where `u` is
+    /// sampled uniformly from `[0, 1)`. With `exponent == 1` this is uniform;
+    /// larger exponents increasingly favour values near `low`, while exponents
+    /// between 0 and 1 favour values near `high`. This is useful e.g. for
+    /// recency-biased access to a collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or if `exponent` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut rng = thread_rng();
+    /// let recent = [5, 4, 3, 2, 1];
+    /// // Usually picks one of the first items
+    /// let i = rng.gen_range_biased(0..recent.len(), 3.0);
+    /// println!("{}", recent[i]);
+    /// ```
+    //
+    // Note: this is feature-gated on std due to usage of f64::powf.
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    fn gen_range_biased(&mut self, range: Range<usize>, exponent: f64) -> usize {
+        assert!(range.start < range.end, "cannot sample empty range");
+        assert!(exponent > 0.0, "exponent is not positive");
+        let width = range.end - range.start;
+        let u: f64 = self.gen();
+        // Rounding may yield `width` for very large ranges; clamp it.
+        let offset = ((width as f64 * u.powf(exponent)) as usize).min(width - 1);
+        range.start + offset
+    }
+
     /// Sample a new value, using the given distribution.
     ///
     /// ### Example
@@ -247,6 +395,27 @@ pub trait Rng: RngCore {
         dest.try_fill(self)
     }
 
+    /// Fill several buffers with random data in one call
+    ///
+    /// `dest` may be a 2- or 3-tuple of mutable references to types
+    /// implementing [`Fill`]; these are filled in order, thus the result is
+    /// the same as calling [`fill`] on each.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{thread_rng, Rng};
+    ///
+    /// let mut a = [0u8; 16];
+    /// let mut b = [0u32; 4];
+    /// thread_rng().fill_all((&mut a[..], &mut b[..]));
+    /// ```
+    ///
+    /// [`fill`]: Rng::fill
+    fn fill_all<T: Fill>(&mut self, mut dest: T) {
+        dest.try_fill(self).unwrap_or_else(|_| panic!("Rng::fill_all failed"))
+    }
+
     /// Return a bool with a probability `p` of being true.
     ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
@@ -300,6 +469,91 @@ pub trait Rng: RngCore {
         let d = distributions::Bernoulli::from_ratio(numerator, denominator).unwrap();
         self.sample(d)
     }
+
+    /// Run `f` speculatively on a clone of this RNG
+    ///
+    /// The RNG is cloned and `f` is applied to the clone; the result is
+    /// returned together with the advanced clone, while `self` is left
+    /// unchanged. To commit to the result, assign the clone back to `self`.
+    ///
+    /// Note that for RNGs sharing state between clones (e.g. [`ThreadRng`])
+    /// the original is *not* protected from changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let (x, advanced) = rng.with_rollback(|rng| rng.gen::<u32>());
+    /// if x % 2 == 0 {
+    ///     rng = advanced; // commit
+    /// }
+    /// # let _ = rng.gen::<u32>();
+    /// ```
+    ///
+    /// [`ThreadRng`]: crate::rngs::ThreadRng
+    fn with_rollback<F, T>(&self, f: F) -> (T, Self)
+    where
+        F: FnOnce(&mut Self) -> T,
+        Self: Clone,
+    {
+        let mut rng = self.clone();
+        let result = f(&mut rng);
+        (result, rng)
+    }
+
+    /// Return a string of exactly `n` ASCII decimal digits, each uniformly
+    /// distributed over `0..=9`.
+    ///
+    /// Leading zeros are kept, thus this is suitable for numeric codes and
+    /// identifiers of fixed length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let pin = rand::thread_rng().gen_digits(6);
+    /// assert_eq!(pin.len(), 6);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn gen_digits(&mut self, n: usize) -> String {
+        let digits = distributions::Uniform::new(b'0', b'9' + 1);
+        (0..n).map(|_| char::from(self.sample(digits))).collect()
+    }
+
+    /// Return an `AtomicU32` initialized to a random value.
+    ///
+    /// This is a shortcut for `AtomicU32::new(rng.gen())`, e.g. to seed
+    /// lock-free data structures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::sync::atomic::Ordering;
+    /// use rand::Rng;
+    ///
+    /// let counter = rand::thread_rng().gen_atomic_u32();
+    /// println!("{}", counter.load(Ordering::Relaxed));
+    /// ```
+    #[cfg(feature = "atomic")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "atomic")))]
+    fn gen_atomic_u32(&mut self) -> AtomicU32 {
+        AtomicU32::new(self.gen())
+    }
+
+    /// Return an `AtomicU64` initialized to a random value.
+    ///
+    /// This is a shortcut for `AtomicU64::new(rng.gen())`; see also
+    /// [`Rng::gen_atomic_u32`].
+    #[cfg(feature = "atomic")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "atomic")))]
+    fn gen_atomic_u64(&mut self) -> AtomicU64 {
+        AtomicU64::new(self.gen())
+    }
 }
 
 impl<R: RngCore + ?Sized> Rng for R {}
@@ -396,6 +650,26 @@ impl_fill!(i8, i16, i32, i64, isize,);
 #[cfg(not(target_os = "emscripten"))]
 impl_fill!(i128);
 
+impl<T: Fill + ?Sized> Fill for &mut T {
+    fn try_fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), Error> {
+        (**self).try_fill(rng)
+    }
+}
+
+macro_rules! impl_fill_tuple {
+    ($($T:ident $n:tt),+) => {
+        impl<$($T: Fill),+> Fill for ($($T,)+) {
+            fn try_fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), Error> {
+                $(self.$n.try_fill(rng)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_fill_tuple!(A 0, B 1);
+impl_fill_tuple!(A 0, B 1, C 2);
+
 #[cfg(feature = "min_const_gen")]
 impl<T, const N: usize> Fill for [T; N]
 where [T]: Fill
@@ -488,6 +762,28 @@ mod test {
         assert_eq!(array, gen);
     }
 
+    #[test]
+    fn test_fill_all() {
+        let mut rng = rng(483);
+        let mut a = [0u8; 32];
+        let mut b = [0u64; 4];
+        rng.fill_all((&mut a[..], &mut b[..]));
+        assert!(a.iter().any(|&x| x != 0));
+        assert!(b.iter().all(|&x| x != 0));
+        assert_ne!(&a[..8], &b[0].to_le_bytes()[..]);
+
+        // Equivalent to filling each in turn
+        let mut rng1 = crate::test::rng(484);
+        let mut rng2 = crate::test::rng(484);
+        let (mut c, mut d, mut e) = ([0u32; 3], [0i16; 5], [0f32; 2]);
+        rng1.fill_all((&mut c, &mut d[..], &mut e));
+        let (mut c2, mut d2, mut e2) = ([0u32; 3], [0i16; 5], [0f32; 2]);
+        rng2.fill(&mut c2);
+        rng2.fill(&mut d2[..]);
+        rng2.fill(&mut e2);
+        assert_eq!((c, d, e), (c2, d2, e2));
+    }
+
     #[test]
     fn test_fill_empty() {
         let mut array = [0u32; 0];
@@ -517,6 +813,113 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_gen_range_int_inclusive() {
+        let mut r = rng(523);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let x: u8 = r.gen_range(1..=6);
+            seen[x as usize - 1] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+        assert_eq!(r.gen_range(7..=7), 7);
+        assert_eq!(r.gen_range(core::u8::MAX..=core::u8::MAX), core::u8::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_inclusive_panic() {
+        #![allow(clippy::reversed_empty_ranges)]
+        let mut r = rng(524);
+        r.gen_range(6..=1);
+    }
+
+    #[test]
+    fn test_try_gen_range() {
+        #![allow(clippy::reversed_empty_ranges)]
+        use crate::distributions::uniform::RangeError;
+        let mut r = rng(525);
+        assert_eq!(r.try_gen_range(5..2), Err::<i32, _>(RangeError));
+        assert_eq!(r.try_gen_range(3..3), Err::<i32, _>(RangeError));
+        assert_eq!(r.try_gen_range(3..=2), Err::<u8, _>(RangeError));
+        assert_eq!(r.try_gen_range(1.0..core::f64::NAN), Err(RangeError));
+        assert_eq!(r.try_gen_range(3..=3), Ok(3));
+
+        let mut r2 = rng(525);
+        for _ in 0..100 {
+            assert_eq!(r.try_gen_range(-10..10), Ok(r2.gen_range(-10..10)));
+        }
+    }
+
+    #[test]
+    fn test_gen_range_open() {
+        let mut r = rng(534);
+        let mut seen_min = false;
+        let mut seen_max = false;
+        for _ in 0..1000 {
+            let x: u8 = r.gen_range_from_start(4);
+            assert!(x < 4);
+            seen_min |= x == 0;
+            let y: u8 = r.gen_range_to_end(252);
+            assert!(y >= 252);
+            seen_max |= y == core::u8::MAX;
+        }
+        assert!(seen_min && seen_max);
+
+        assert_eq!(r.gen_range_to_end(core::u8::MAX), core::u8::MAX);
+        assert_eq!(r.gen_range_from_start(1u8), 0);
+        assert!(r.gen_range::<i32, _>(..-5) < -5);
+        assert!(r.gen_range::<i64, _>(5..) >= 5);
+        assert!(r.try_gen_range(..0u8).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_from_start_panic() {
+        let mut r = rng(534);
+        r.gen_range_from_start(core::u8::MIN);
+    }
+
+    #[test]
+    fn test_gen_range_counted() {
+        let mut r = rng(536);
+        let mut r2 = rng(536);
+        for _ in 0..1000 {
+            let (x, rejected) = r.gen_range_counted(10u8, 10 + 64);
+            assert_eq!(rejected, 0);
+            assert_eq!(x, r2.gen_range(10u8..10 + 64));
+            assert_eq!(r.gen_range_counted(-128i16, 128).1, 0);
+            r2.gen_range(-128i16..128);
+            let (x, _) = r.gen_range_counted(-5i64, 1000);
+            assert_eq!(x, r2.gen_range(-5i64..1000));
+        }
+
+        // A range just above 2^31 rejects almost half of all candidates.
+        let high = (1 << 31) + 1;
+        let mut total = 0;
+        for _ in 0..1000 {
+            let (x, rejected) = r.gen_range_counted(0u32, high);
+            assert!(x < high);
+            total += rejected;
+        }
+        assert!(total > 500);
+        assert_eq!(r.gen_range_counted(1.0, 2.0).1, 0);
+
+        // Only two `f32` values lie in this range; about half of all
+        // candidates round up to `high`.
+        let (low, high) = (1e8f32, 1e8 + 8.0);
+        let mut r = rng(537);
+        let mut r2 = rng(537);
+        let mut total = 0;
+        for _ in 0..1000 {
+            let (x, rejected) = r.gen_range_counted(low, high);
+            assert_eq!(x, low);
+            assert_eq!(x, r2.gen_range(low..high));
+            total += rejected;
+        }
+        assert!(total > 500);
+    }
+
     #[test]
     fn test_gen_range_float() {
         let mut r = rng(101);
@@ -548,6 +951,35 @@ mod test {
         r.gen_range(5..2);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gen_range_biased() {
+        let mut r = rng(104);
+        let mut counts = [0u32; 10];
+        for _ in 0..1000 {
+            let a = r.gen_range_biased(10..20, 4.0);
+            assert!((10..20).contains(&a));
+            counts[a - 10] += 1;
+        }
+        // P(a == 10) = 0.1^(1/4) ~= 0.56; P(a >= 15) = 1 - 0.5^(1/4) ~= 0.16
+        assert!(counts[0] > 500);
+        assert!(counts[5..].iter().sum::<u32>() < 200);
+
+        assert_eq!(r.gen_range_biased(7..8, 100.0), 7);
+        for _ in 0..100 {
+            let a = r.gen_range_biased(0..usize::max_value(), 0.1);
+            assert!(a < usize::max_value());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn test_gen_range_biased_panic_exponent() {
+        let mut r = rng(104);
+        r.gen_range_biased(0..10, 0.0);
+    }
+
     #[test]
     fn test_gen_bool() {
         #![allow(clippy::bool_assert_comparison)]
@@ -582,6 +1014,42 @@ mod test {
         let _c: u8 = Standard.sample(&mut r);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_gen_digits() {
+        let mut r = rng(520);
+        for &n in &[0, 1, 6, 100] {
+            let s = r.gen_digits(n);
+            assert_eq!(s.len(), n);
+            assert!(s.chars().all(|c| c.is_ascii_digit()));
+        }
+
+        let mut seen = [false; 10];
+        for c in r.gen_digits(200).bytes() {
+            seen[(c - b'0') as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+
+    #[test]
+    #[cfg(feature = "atomic")]
+    fn test_gen_atomic() {
+        use core::sync::atomic::Ordering;
+
+        let mut r = rng(516);
+        let a = r.gen_atomic_u32().load(Ordering::Relaxed);
+        let b = r.gen_atomic_u32().load(Ordering::Relaxed);
+        assert_ne!(a, b);
+        let a = r.gen_atomic_u64().load(Ordering::Relaxed);
+        let b = r.gen_atomic_u64().load(Ordering::Relaxed);
+        assert_ne!(a, b);
+
+        let mut r2 = rng(516);
+        r2.gen::<[u32; 2]>();
+        r2.gen::<u64>();
+        assert_eq!(b, r2.gen::<u64>());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_gen_ratio_average() {
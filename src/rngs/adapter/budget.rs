@@ -0,0 +1,127 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A wrapper limiting the output of another RNG.
+
+use rand_core::{Error, RngCore};
+
+/// An RNG that forwards to an inner RNG up to a total budget of bytes.
+///
+/// Each method consumes budget according to the number of bytes output:
+/// 4 for `next_u32`, 8 for `next_u64` and `dest.len()` for `fill_bytes`.
+/// Once a request exceeds the remaining budget, [`try_fill_bytes`] returns
+/// an error, while the other [`RngCore`] methods panic. Requests which fail
+/// consume no budget and draw nothing from the inner RNG.
+///
+/// This may be used to limit (or detect excessive) consumption of randomness
+/// by untrusted code.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::rngs::adapter::BudgetRng;
+///
+/// let mut rng = BudgetRng::new(thread_rng(), 16);
+/// let mut buf = [0u8; 12];
+/// assert!(rng.try_fill_bytes(&mut buf).is_ok());
+/// assert_eq!(rng.remaining(), 4);
+/// assert!(rng.try_fill_bytes(&mut buf).is_err());
+/// ```
+///
+/// [`try_fill_bytes`]: RngCore::try_fill_bytes
+#[derive(Clone, Debug)]
+pub struct BudgetRng<R> {
+    inner: R,
+    remaining: u64,
+}
+
+impl<R: RngCore> BudgetRng<R> {
+    /// Create a new `BudgetRng` allowing at most `budget` bytes to be drawn
+    /// from `inner`.
+    pub fn new(inner: R, budget: u64) -> BudgetRng<R> {
+        BudgetRng { inner, remaining: budget }
+    }
+
+    /// The number of bytes which may still be drawn.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Consume self, returning the inner RNG.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn consume(&mut self, n: usize) -> Result<(), Error> {
+        let n = n as u64;
+        if n > self.remaining {
+            return Err(Error::new("BudgetRng: byte budget exhausted"));
+        }
+        self.remaining -= n;
+        Ok(())
+    }
+}
+
+impl<R: RngCore> RngCore for BudgetRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.consume(4).unwrap_or_else(|err| panic!("{}", err));
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.consume(8).unwrap_or_else(|err| panic!("{}", err));
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.consume(dest.len())?;
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+
+    #[test]
+    fn test_budget() {
+        let mut rng = BudgetRng::new(StepRng::new(1, 1), 20);
+        assert_eq!(rng.next_u64(), 1);
+        assert_eq!(rng.next_u32(), 2);
+        let mut buf = [0u8; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(rng.remaining(), 0);
+
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+        // Empty requests still succeed
+        assert!(rng.try_fill_bytes(&mut []).is_ok());
+    }
+
+    #[test]
+    fn test_budget_partial() {
+        let mut rng = BudgetRng::new(StepRng::new(0, 1), 10);
+        let mut buf = [0u8; 11];
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+        assert_eq!(rng.remaining(), 10);
+        assert!(rng.try_fill_bytes(&mut buf[..10]).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_budget_panic() {
+        let mut rng = BudgetRng::new(StepRng::new(0, 1), 7);
+        rng.next_u64();
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A buffering wrapper around a boxed RNG.
+
+use core::fmt;
+use std::boxed::Box;
+
+use rand_core::{impls, Error, RngCore};
+
+/// Size of the buffer, in bytes
+const BUF_LEN: usize = 256;
+
+/// An RNG buffering the output of a boxed, dynamically dispatched RNG.
+///
+/// Each call through a `dyn RngCore` is a virtual call, which is significant
+/// overhead when sampling many small values. This wrapper instead fetches
+/// 256 bytes per virtual call and serves small requests from its buffer.
+/// Requests of at least the buffer size bypass the buffer.
+///
+/// The output is the byte stream of the inner RNG's `fill_bytes`, read in
+/// little-endian order by `next_u32` and `next_u64`. For block RNGs such as
+/// [`StdRng`] this matches the output of the unbuffered RNG, provided all
+/// requests are of a multiple of 4 bytes.
+///
+/// # Error handling
+///
+/// Errors from the inner RNG are forwarded by [`try_fill_bytes`]; the other
+/// [`RngCore`] methods will panic in case of an error.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::rngs::adapter::BufferedDynRng;
+///
+/// let inner: Box<dyn RngCore> = Box::new(thread_rng());
+/// let mut rng = BufferedDynRng::new(inner);
+/// println!("{}", rng.gen::<u8>());
+/// ```
+///
+/// [`StdRng`]: crate::rngs::StdRng
+/// [`try_fill_bytes`]: RngCore::try_fill_bytes
+pub struct BufferedDynRng {
+    inner: Box<dyn RngCore>,
+    buf: [u8; BUF_LEN],
+    pos: usize,
+}
+
+impl BufferedDynRng {
+    /// Create a new `BufferedDynRng` buffering the output of `inner`.
+    pub fn new(inner: Box<dyn RngCore>) -> BufferedDynRng {
+        BufferedDynRng {
+            inner,
+            buf: [0; BUF_LEN],
+            pos: BUF_LEN,
+        }
+    }
+
+    /// Consume self, returning the inner RNG.
+    ///
+    /// Any buffered bytes are discarded.
+    pub fn into_inner(self) -> Box<dyn RngCore> {
+        self.inner
+    }
+}
+
+impl fmt::Debug for BufferedDynRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedDynRng")
+            .field("buffered", &(BUF_LEN - self.pos))
+            .finish()
+    }
+}
+
+impl RngCore for BufferedDynRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap_or_else(|err| {
+            panic!("BufferedDynRng failed to fill bytes; error: {}", err)
+        });
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        while filled < dest.len() {
+            if self.pos == BUF_LEN {
+                if dest.len() - filled >= BUF_LEN {
+                    return self.inner.try_fill_bytes(&mut dest[filled..]);
+                }
+                self.inner.try_fill_bytes(&mut self.buf)?;
+                self.pos = 0;
+            }
+            let n = (BUF_LEN - self.pos).min(dest.len() - filled);
+            dest[filled..filled + n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+            self.pos += n;
+            filled += n;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::StdRng;
+    use crate::SeedableRng;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Counts calls to the inner RNG
+    struct CountingRng(StdRng, Rc<Cell<usize>>);
+
+    impl RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            self.1.set(self.1.get() + 1);
+            self.0.next_u32()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.1.set(self.1.get() + 1);
+            self.0.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.1.set(self.1.get() + 1);
+            self.0.fill_bytes(dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.1.set(self.1.get() + 1);
+            self.0.try_fill_bytes(dest)
+        }
+    }
+
+    #[test]
+    fn test_buffered_matches_unbuffered() {
+        let mut plain: Box<dyn RngCore> = Box::new(StdRng::seed_from_u64(514));
+        let mut rng = BufferedDynRng::new(Box::new(StdRng::seed_from_u64(514)));
+        for i in 0..200 {
+            assert_eq!(rng.next_u32(), plain.next_u32());
+            assert_eq!(rng.next_u64(), plain.next_u64());
+            // Small and large (unbuffered) requests
+            let len = if i % 10 == 0 { 1000 } else { 4 * (i % 7) };
+            let mut buf1 = [0u8; 1000];
+            let mut buf2 = [0u8; 1000];
+            rng.fill_bytes(&mut buf1[..len]);
+            plain.fill_bytes(&mut buf2[..len]);
+            assert_eq!(&buf1[..], &buf2[..]);
+        }
+    }
+
+    #[test]
+    fn test_buffered_calls() {
+        let calls = Rc::new(Cell::new(0));
+        let inner = CountingRng(StdRng::seed_from_u64(515), calls.clone());
+        let mut rng = BufferedDynRng::new(Box::new(inner));
+        for _ in 0..BUF_LEN {
+            rng.next_u32();
+        }
+        // Each inner call yields 64 `u32` values
+        assert_eq!(calls.get(), 4);
+    }
+}
@@ -8,9 +8,17 @@
 
 //! Wrappers / adapters forming RNGs
 
+mod budget;
+mod buffered;
 mod read;
 mod reseeding;
+mod throttled;
+mod whitening;
 
+pub use self::budget::BudgetRng;
+pub use self::buffered::BufferedDynRng;
 #[allow(deprecated)]
-pub use self::read::{ReadError, ReadRng};
+pub use self::read::{BufferedReadRng, ReadError, ReadRng};
 pub use self::reseeding::ReseedingRng;
+pub use self::throttled::ThrottledRng;
+pub use self::whitening::{WhiteningMode, WhiteningRng};
@@ -12,7 +12,7 @@
 #![allow(deprecated)]
 
 use std::fmt;
-use std::io::Read;
+use std::io::{self, Read};
 
 use rand_core::{impls, Error, RngCore};
 
@@ -76,6 +76,125 @@ impl<R: Read> RngCore for ReadRng<R> {
     }
 }
 
+/// Size of the `BufferedReadRng` buffer, in bytes
+const BUF_LEN: usize = 64;
+
+/// An RNG reading random bytes from any type supporting [`std::io::Read`],
+/// through an internal buffer.
+///
+/// Unlike [`ReadRng`], which reads exactly the requested bytes on each call
+/// (e.g. a syscall per `next_u32` when reading from a file), this reads up to
+/// 64 bytes at a time and serves small requests from its buffer. Requests of
+/// at least 64 bytes read directly from the reader once the buffer is empty.
+///
+/// The output is the byte stream of the reader, exactly as for [`ReadRng`]:
+/// in particular `next_u32` and `next_u64` read bytes in little-endian order.
+/// Reads only block until the current request can be served, but bytes read
+/// beyond the request remain buffered and are lost by [`into_inner`].
+///
+/// # Panics
+///
+/// Errors from the underlying reader, including when it does not have enough
+/// data, will only be reported through [`try_fill_bytes`], wrapping the
+/// [`std::io::Error`]. The other [`RngCore`] methods will panic in case of an
+/// error.
+///
+/// [`into_inner`]: BufferedReadRng::into_inner
+/// [`try_fill_bytes`]: RngCore::try_fill_bytes
+pub struct BufferedReadRng<R> {
+    reader: R,
+    buf: [u8; BUF_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<R: Read> BufferedReadRng<R> {
+    /// Create a new `BufferedReadRng` from a `Read`.
+    pub fn new(reader: R) -> BufferedReadRng<R> {
+        BufferedReadRng {
+            reader,
+            buf: [0; BUF_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Consume self, returning the reader.
+    ///
+    /// Any buffered bytes are discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    // Refill the (empty) buffer with at least `min` bytes.
+    fn refill(&mut self, min: usize) -> io::Result<()> {
+        self.pos = 0;
+        self.len = 0;
+        while self.len < min {
+            match self.reader.read(&mut self.buf[self.len..]) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => self.len += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for BufferedReadRng<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BufferedReadRng")
+            .field("reader", &self.reader)
+            .field("buffered", &(self.len - self.pos))
+            .finish()
+    }
+}
+
+impl<R: Read> RngCore for BufferedReadRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap_or_else(|err| {
+            panic!(
+                "reading random bytes from Read implementation failed; error: {}",
+                err
+            )
+        });
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        loop {
+            let n = (self.len - self.pos).min(dest.len() - filled);
+            dest[filled..filled + n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+            self.pos += n;
+            filled += n;
+            let rest = dest.len() - filled;
+            if rest == 0 {
+                return Ok(());
+            }
+            if rest >= BUF_LEN {
+                return self.reader
+                    .read_exact(&mut dest[filled..])
+                    .map_err(Error::new);
+            }
+            self.refill(rest).map_err(Error::new)?;
+        }
+    }
+}
+
 /// `ReadRng` error type
 #[derive(Debug)]
 #[deprecated(since="0.8.4")]
@@ -98,8 +217,10 @@ impl std::error::Error for ReadError {
 mod test {
     use std::println;
 
-    use super::ReadRng;
+    use super::{BufferedReadRng, ReadRng, BUF_LEN};
     use crate::RngCore;
+    use std::io::{self, Read};
+    use std::vec::Vec;
 
     #[test]
     fn test_reader_rng_u64() {
@@ -147,4 +268,64 @@ mod test {
         assert!(result.is_err());
         println!("Error: {}", result.unwrap_err());
     }
+
+    /// A reader returning at most `max` bytes per call, counting calls
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        max: usize,
+        calls: usize,
+    }
+
+    impl<'a> ShortReader<'a> {
+        fn new(data: &'a [u8], max: usize) -> Self {
+            ShortReader { data, max, calls: 0 }
+        }
+    }
+
+    impl<'a> Read for ShortReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.calls += 1;
+            let n = buf.len().min(self.max);
+            self.data.read(&mut buf[..n])
+        }
+    }
+
+    #[test]
+    fn test_buffered_reader_rng_matches_unbuffered() {
+        let v: Vec<u8> = (0..2000u32).map(|i| (i * 7 + i / 256) as u8).collect();
+        let mut plain = ReadRng::new(&v[..]);
+        let mut rng = BufferedReadRng::new(ShortReader::new(&v[..], 7));
+        for i in 0..60 {
+            assert_eq!(rng.next_u32(), plain.next_u32());
+            assert_eq!(rng.next_u64(), plain.next_u64());
+            // Small, odd-sized and large (unbuffered) requests
+            let len = if i % 10 == 0 { 100 } else { i % 9 };
+            let mut buf1 = [0u8; 100];
+            let mut buf2 = [0u8; 100];
+            rng.fill_bytes(&mut buf1[..len]);
+            plain.fill_bytes(&mut buf2[..len]);
+            assert_eq!(&buf1[..], &buf2[..]);
+        }
+    }
+
+    #[test]
+    fn test_buffered_reader_rng_calls() {
+        let v = [0u8; 1000];
+        let mut rng = BufferedReadRng::new(ShortReader::new(&v[..], BUF_LEN));
+        for _ in 0..BUF_LEN / 4 {
+            rng.next_u32();
+        }
+        assert_eq!(rng.into_inner().calls, 1);
+    }
+
+    #[test]
+    fn test_buffered_reader_rng_insufficient_bytes() {
+        let v = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut rng = BufferedReadRng::new(&v[..]);
+        assert_eq!(rng.next_u64(), 0x0807_0605_0403_0201);
+        let mut w = [0u8; 3];
+        let err = rng.try_fill_bytes(&mut w).unwrap_err();
+        let err = err.inner().downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
 }
@@ -0,0 +1,114 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A wrapper delaying the output of another RNG.
+
+use std::thread;
+use std::time::Duration;
+
+use rand_core::{Error, RngCore};
+
+/// An RNG that sleeps for a fixed duration before forwarding each request to
+/// an inner RNG.
+///
+/// Every [`RngCore`] method call sleeps once, regardless of the number of
+/// bytes requested; the output is that of the inner RNG. This simulates a
+/// slow source such as a hardware RNG, e.g. to reproduce timing-sensitive
+/// bugs in code waiting for randomness. It is intended for testing only.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use rand::prelude::*;
+/// use rand::rngs::adapter::ThrottledRng;
+///
+/// let mut rng = ThrottledRng::new(thread_rng(), Duration::from_millis(1));
+/// println!("{}", rng.gen::<u32>());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThrottledRng<R> {
+    inner: R,
+    delay: Duration,
+}
+
+impl<R: RngCore> ThrottledRng<R> {
+    /// Create a new `ThrottledRng` sleeping for `delay` before each request
+    /// to `inner`.
+    pub fn new(inner: R, delay: Duration) -> ThrottledRng<R> {
+        ThrottledRng { inner, delay }
+    }
+
+    /// The delay before each request.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Set the delay before each request.
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// Consume self, returning the inner RNG.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: RngCore> RngCore for ThrottledRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        thread::sleep(self.delay);
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        thread::sleep(self.delay);
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        thread::sleep(self.delay);
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        thread::sleep(self.delay);
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+    use std::time::Instant;
+
+    #[test]
+    fn test_throttled() {
+        let delay = Duration::from_millis(2);
+        let mut rng = ThrottledRng::new(StepRng::new(1, 1), delay);
+        let mut plain = StepRng::new(1, 1);
+
+        let start = Instant::now();
+        assert_eq!(rng.next_u64(), plain.next_u64());
+        assert_eq!(rng.next_u32(), plain.next_u32());
+        let (mut buf1, mut buf2) = ([0u8; 12], [0u8; 12]);
+        rng.fill_bytes(&mut buf1);
+        plain.fill_bytes(&mut buf2);
+        assert_eq!(buf1, buf2);
+        assert!(rng.try_fill_bytes(&mut buf1).is_ok());
+        assert!(start.elapsed() >= 4 * delay);
+
+        rng.set_delay(Duration::from_millis(0));
+        assert_eq!(rng.delay(), Duration::from_millis(0));
+        plain.fill_bytes(&mut buf2);
+        assert_eq!(buf1, buf2);
+        assert_eq!(rng.into_inner().next_u64(), plain.next_u64());
+    }
+}
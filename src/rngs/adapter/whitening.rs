@@ -0,0 +1,234 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A wrapper removing bias from the output of another RNG.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use rand_core::{impls, Error, RngCore};
+
+/// Maximal number of input bytes consumed without producing a single output
+/// bit before a von Neumann extractor gives up.
+const STUCK_LIMIT: usize = 1024;
+
+/// The method used by [`WhiteningRng`] to condition its input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WhiteningMode {
+    /// Von Neumann debiasing.
+    ///
+    /// Input bits are consumed in pairs; `01` yields `0`, `10` yields `1` and
+    /// equal pairs are discarded. The output is unbiased provided input bits
+    /// are independent (but not necessarily uniform). This consumes at least
+    /// four input bytes per output byte, and more the more biased the input.
+    VonNeumann,
+    /// Conditioning with a keyed hash function.
+    ///
+    /// Each 8 output bytes are the SipHash of `key` and 16 input bytes. The
+    /// output is close to uniform provided each 16 input bytes hold well over
+    /// 64 bits of entropy; input bits need not be independent.
+    ///
+    /// The hash function is that of [`DefaultHasher`], thus output is *not*
+    /// guaranteed to be reproducible across Rust versions.
+    Hash {
+        /// Key mixed into each hash
+        key: u64,
+    },
+}
+
+/// An RNG that removes bias from the output of another RNG.
+///
+/// This is intended for raw output of hardware random sources, which is
+/// frequently biased or correlated. The inner RNG's output is conditioned
+/// according to a [`WhiteningMode`]. Note that no conditioning can create
+/// entropy; it only concentrates the entropy of its input.
+///
+/// # Error handling
+///
+/// Errors from the inner RNG are forwarded by [`try_fill_bytes`]. In
+/// [`WhiteningMode::VonNeumann`], an error is also reported when the inner
+/// RNG appears stuck, i.e. 1024 input bytes yield no output. The other
+/// [`RngCore`] methods will panic in case of an error.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::rngs::adapter::{WhiteningMode, WhiteningRng};
+///
+/// let mut rng = WhiteningRng::new(thread_rng(), WhiteningMode::VonNeumann);
+/// println!("{}", rng.gen::<u32>());
+/// ```
+///
+/// [`try_fill_bytes`]: RngCore::try_fill_bytes
+#[derive(Clone, Debug)]
+pub struct WhiteningRng<R> {
+    inner: R,
+    mode: WhiteningMode,
+}
+
+impl<R: RngCore> WhiteningRng<R> {
+    /// Create a new `WhiteningRng` conditioning the output of `inner`.
+    pub fn new(inner: R, mode: WhiteningMode) -> WhiteningRng<R> {
+        WhiteningRng { inner, mode }
+    }
+
+    /// Consume self, returning the inner RNG.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn fill_von_neumann(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let mut buf = [0u8; 64];
+        let mut buf_pos = buf.len();
+        let mut stuck = 0;
+        for byte in dest.iter_mut() {
+            let mut out = 0u8;
+            let mut bits = 0;
+            while bits < 8 {
+                if buf_pos == buf.len() {
+                    self.inner.try_fill_bytes(&mut buf)?;
+                    buf_pos = 0;
+                }
+                let x = buf[buf_pos];
+                buf_pos += 1;
+                let produced = bits;
+                // Four bit pairs per input byte, least significant first;
+                // of each unequal pair output the first bit
+                for i in 0..4 {
+                    let pair = (x >> (2 * i)) & 0b11;
+                    if bits < 8 && (pair == 0b01 || pair == 0b10) {
+                        out |= (pair & 1) << bits;
+                        bits += 1;
+                    }
+                }
+                if bits == produced {
+                    stuck += 1;
+                    if stuck >= STUCK_LIMIT {
+                        return Err(Error::new("WhiteningRng: inner RNG appears stuck"));
+                    }
+                } else {
+                    stuck = 0;
+                }
+            }
+            *byte = out;
+        }
+        Ok(())
+    }
+
+    fn fill_hash(&mut self, dest: &mut [u8], key: u64) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(8) {
+            let mut input = [0u8; 16];
+            self.inner.try_fill_bytes(&mut input)?;
+            let mut hasher = DefaultHasher::new();
+            hasher.write_u64(key);
+            hasher.write(&input);
+            let out = hasher.finish().to_le_bytes();
+            chunk.copy_from_slice(&out[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+impl<R: RngCore> RngCore for WhiteningRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap_or_else(|err| {
+            panic!("WhiteningRng failed to fill bytes; error: {}", err)
+        });
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self.mode {
+            WhiteningMode::VonNeumann => self.fill_von_neumann(dest),
+            WhiteningMode::Hash { key } => self.fill_hash(dest, key),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rngs::mock::StepRng;
+    use crate::Rng;
+
+    /// Yields bits which are set with probability 0.8
+    struct BiasedRng<R>(R);
+
+    impl<R: RngCore> RngCore for BiasedRng<R> {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest.iter_mut() {
+                *byte = 0;
+                for i in 0..8 {
+                    *byte |= (self.0.gen_bool(0.8) as u8) << i;
+                }
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn ones_ratio<R: RngCore>(rng: &mut R) -> f64 {
+        let mut buf = [0u8; 4096];
+        rng.fill_bytes(&mut buf);
+        let ones: u32 = buf.iter().map(|x| x.count_ones()).sum();
+        ones as f64 / (buf.len() * 8) as f64
+    }
+
+    #[test]
+    fn test_whitening_balance() {
+        let mut biased = BiasedRng(crate::test::rng(490));
+        assert!(ones_ratio(&mut biased) > 0.75);
+
+        for &mode in &[WhiteningMode::VonNeumann, WhiteningMode::Hash { key: 490 }] {
+            let mut rng = WhiteningRng::new(BiasedRng(crate::test::rng(491)), mode);
+            let ratio = ones_ratio(&mut rng);
+            assert!((ratio - 0.5).abs() < 0.01, "{:?}: {}", mode, ratio);
+        }
+    }
+
+    #[test]
+    fn test_whitening_von_neumann_fixed() {
+        // 0b1001_1001: pairs (low bit first) 10, 01, 10, 01 yield 1, 0, 1, 0
+        let mut rng = WhiteningRng::new(
+            StepRng::new(0x9999_9999_9999_9999, 0), WhiteningMode::VonNeumann);
+        let mut buf = [0u8; 3];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0b0101_0101; 3]);
+    }
+
+    #[test]
+    fn test_whitening_stuck() {
+        let mut rng = WhiteningRng::new(StepRng::new(0, 0), WhiteningMode::VonNeumann);
+        let mut buf = [0u8; 1];
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+
+        // Hash conditioning cannot detect this
+        let mut rng = WhiteningRng::new(StepRng::new(0, 0), WhiteningMode::Hash { key: 0 });
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A seekable counter-based generator
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{Error, RngCore, SeedableRng};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+const PHI: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The SplitMix64 finalizer, a bijective mixing function
+#[inline]
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// A deterministic, seekable counter-based generator
+///
+/// Each `u64` output is a hash of the seed and a 64-bit counter, which is
+/// incremented after every output. Thus the `n`-th output can be computed
+/// directly by seeking with [`CounterRng::set_counter`], and disjoint counter
+/// ranges may be assigned to parallel workers while the combined output stays
+/// reproducible.
+///
+/// The hash is a multiplication and the SplitMix64 finalizer, which is cheap
+/// and passes common statistical tests, but this generator is **not**
+/// cryptographically secure. The period is `2^64` outputs.
+///
+/// `next_u32` consumes a full `u64` output (one counter step), returning its
+/// high half; `fill_bytes` consumes one step per 8 bytes.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::rngs::CounterRng;
+///
+/// let mut a = CounterRng::new(42);
+/// let _skipped: u64 = a.gen();
+///
+/// // Seek directly to output 1
+/// let mut b = CounterRng::new(42);
+/// b.set_counter(1);
+/// assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CounterRng {
+    key: u64,
+    counter: u64,
+}
+
+impl CounterRng {
+    /// Create a new `CounterRng` from `seed`, with counter 0.
+    #[inline]
+    pub fn new(seed: u64) -> CounterRng {
+        CounterRng { key: mix64(seed), counter: 0 }
+    }
+
+    /// Get the counter, i.e. the number of `u64` outputs generated since
+    /// the counter was last set.
+    #[inline]
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Set the counter, seeking to the corresponding position in the output
+    /// stream.
+    #[inline]
+    pub fn set_counter(&mut self, counter: u64) {
+        self.counter = counter;
+    }
+}
+
+impl RngCore for CounterRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let x = mix64(self.counter.wrapping_mul(PHI) ^ self.key);
+        self.counter = self.counter.wrapping_add(1);
+        x
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for CounterRng {
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: [u8; 8]) -> CounterRng {
+        let mut state = [0];
+        read_u64_into(&seed, &mut state);
+        CounterRng::new(state[0])
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> CounterRng {
+        CounterRng::new(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_rng_same_counter() {
+        let mut a = CounterRng::new(510);
+        let mut b = CounterRng::new(510);
+        a.set_counter(1234);
+        b.set_counter(1234);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_eq!(a.get_counter(), 1244);
+
+        let mut c = CounterRng::new(511);
+        c.set_counter(1234);
+        assert_ne!(a.next_u64(), c.next_u64());
+    }
+
+    #[test]
+    fn test_counter_rng_seek() {
+        let mut seq = CounterRng::seed_from_u64(7);
+        let mut outputs = [0u64; 20];
+        for x in outputs.iter_mut() {
+            *x = seq.next_u64();
+        }
+        assert_eq!(seq.get_counter(), 20);
+
+        let mut rng = CounterRng::from_seed(7u64.to_le_bytes());
+        for &i in &[13u64, 0, 19, 5] {
+            rng.set_counter(i);
+            assert_eq!(rng.next_u64(), outputs[i as usize]);
+        }
+
+        // fill_bytes consumes one counter step per 8 bytes
+        rng.set_counter(2);
+        let mut buf = [0u8; 16];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(&buf[..8], &outputs[2].to_le_bytes());
+        assert_eq!(&buf[8..], &outputs[3].to_le_bytes());
+        assert_eq!(rng.get_counter(), 4);
+    }
+
+    #[test]
+    fn test_counter_rng_wrap() {
+        let mut rng = CounterRng::new(0);
+        let first = rng.next_u64();
+        rng.set_counter(core::u64::MAX);
+        rng.next_u64();
+        assert_eq!(rng.get_counter(), 0);
+        assert_eq!(rng.next_u64(), first);
+    }
+}
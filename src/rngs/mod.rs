@@ -64,6 +64,12 @@
 //!     periodic reseeding.
 //! -   [`SmallRng`] is an **insecure** PRNG designed to be fast, simple, require
 //!     little memory, and have good output quality.
+//! -   [`CounterRng`] is an **insecure**, reproducible PRNG whose output
+//!     stream can be seeked cheaply, e.g. to split work between threads.
+//! -   [`SplitMix64`] is a tiny **insecure**, reproducible PRNG, mainly
+//!     useful to expand a `u64` seed for other generators.
+//! -   [`Xoroshiro128Plus`] is a fast **insecure** PRNG whose sequence can be
+//!     split into non-overlapping substreams with `jump`.
 //!
 //! The algorithms selected for [`StdRng`] and [`SmallRng`] may change in any
 //! release and may be platform-dependent, therefore they should be considered
@@ -99,6 +105,9 @@
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 #[cfg(feature = "std")] pub mod adapter;
 
+mod counter;
+mod splitmix64;
+mod xoroshiro128plus;
 pub mod mock; // Public so we don't export `StepRng` directly, making it a bit
               // more clear it is intended for testing.
 
@@ -111,6 +120,9 @@ mod xoshiro128plusplus;
 #[cfg(feature = "std_rng")] mod std;
 #[cfg(all(feature = "std", feature = "std_rng"))] pub(crate) mod thread;
 
+pub use self::counter::CounterRng;
+pub use self::splitmix64::SplitMix64;
+pub use self::xoroshiro128plus::Xoroshiro128Plus;
 #[cfg(feature = "small_rng")] pub use self::small::SmallRng;
 #[cfg(feature = "std_rng")] pub use self::std::StdRng;
 #[cfg(all(feature = "std", feature = "std_rng"))] pub use self::thread::ThreadRng;
@@ -9,6 +9,8 @@
 //! A small fast RNG
 
 use rand_core::{Error, RngCore, SeedableRng};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
 
 #[cfg(target_pointer_width = "64")]
 type Rng = super::xoshiro256plusplus::Xoshiro256PlusPlus;
@@ -35,6 +37,10 @@ type Rng = super::xoshiro128plusplus::Xoshiro128PlusPlus;
 /// external crate, e.g. [rand_xoshiro] or [rand_chacha].
 /// Refer also to [The Book](https://rust-random.github.io/book/guide-rngs.html).
 ///
+/// With the `serde1` feature, `SmallRng` supports (de)serialization of its
+/// exact state. As above, the serialized form is not portable across
+/// platforms or library versions.
+///
 /// The PRNG algorithm in `SmallRng` is chosen to be efficient on the current
 /// platform, without consideration for cryptography or security. The size of
 /// its state is much smaller than [`StdRng`]. The current algorithm is
@@ -78,6 +84,7 @@ type Rng = super::xoshiro128plusplus::Xoshiro128PlusPlus;
 /// [rand_xoshiro]: https://crates.io/crates/rand_xoshiro
 #[cfg_attr(doc_cfg, doc(cfg(feature = "small_rng")))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct SmallRng(Rng);
 
 impl RngCore for SmallRng {
@@ -115,3 +122,22 @@ impl SeedableRng for SmallRng {
         Rng::from_rng(rng).map(SmallRng)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn test_serialization_small_rng() {
+        use super::SmallRng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(505);
+        rng.next_u64();
+        let mut de_rng: SmallRng =
+            bincode::deserialize(&bincode::serialize(&rng).unwrap()).unwrap();
+        assert_eq!(rng, de_rng);
+        for _ in 0..16 {
+            assert_eq!(rng.next_u64(), de_rng.next_u64());
+        }
+    }
+}
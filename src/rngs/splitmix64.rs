@@ -0,0 +1,128 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The SplitMix64 generator
+
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{Error, RngCore, SeedableRng};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// A SplitMix64 random number generator.
+///
+/// SplitMix64 has a 64-bit state, incremented by a fixed odd constant per
+/// output which is then passed through a mixing function. It is tiny and
+/// fast, and is commonly used to expand a single `u64` seed into the larger
+/// state of other generators (as done by `SmallRng::seed_from_u64`). It
+/// is not suitable for cryptographic purposes, and with its period of `2^64`
+/// it should not be used for long simulations.
+///
+/// The algorithm used here is translated from [the `splitmix64.c`
+/// reference source code](http://xoshiro.di.unimi.it/splitmix64.c) by
+/// Sebastiano Vigna.
+///
+/// `next_u32` returns the high half of a `u64` output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create a new `SplitMix64` with the given state.
+    ///
+    /// All states, including 0, are valid.
+    #[inline]
+    pub fn new(state: u64) -> SplitMix64 {
+        SplitMix64 { state }
+    }
+}
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    /// Create a new `SplitMix64` from the little-endian state `seed`.
+    #[inline]
+    fn from_seed(seed: [u8; 8]) -> SplitMix64 {
+        let mut state = [0];
+        read_u64_into(&seed, &mut state);
+        SplitMix64::new(state[0])
+    }
+
+    /// Create a new `SplitMix64` using `state` as the state directly.
+    #[inline]
+    fn seed_from_u64(state: u64) -> SplitMix64 {
+        SplitMix64::new(state)
+    }
+}
+
+impl RngCore for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = SplitMix64::from_seed([0; 8]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/splitmix64.c
+        let expected = [
+            0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4, 0x06c45d188009454f,
+            0xf88bb8a8724c81ec, 0x1b39896a51a8749b, 0x53cb9f0c747ea2ea,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn seeding() {
+        let mut a = SplitMix64::seed_from_u64(0x0123_4567_89ab_cdef);
+        let mut b = SplitMix64::from_seed(0x0123_4567_89ab_cdefu64.to_le_bytes());
+        let mut c = SplitMix64::new(0x0123_4567_89ab_cdef);
+        for _ in 0..10 {
+            let x = a.next_u64();
+            assert_eq!(b.next_u64(), x);
+            assert_eq!(c.next_u32(), (x >> 32) as u32);
+        }
+
+        // fill_bytes is little-endian, using next_u32 for a 4-byte tail
+        let mut a = SplitMix64::new(0);
+        let mut buf = [0u8; 12];
+        a.fill_bytes(&mut buf);
+        assert_eq!(&buf[..8], &0xe220_a839_7b1d_cdafu64.to_le_bytes());
+        assert_eq!(&buf[8..], &0x6e78_9e6au32.to_le_bytes());
+    }
+}
@@ -9,6 +9,8 @@
 //! The standard RNG
 
 use crate::{CryptoRng, Error, RngCore, SeedableRng};
+#[cfg(all(feature = "std_rng_serde1", not(target_os = "emscripten")))]
+use serde::{Deserialize, Serialize};
 
 #[cfg(all(any(test, feature = "std"), not(target_os = "emscripten")))]
 pub(crate) use rand_chacha::ChaCha12Core as Core;
@@ -31,10 +33,18 @@ pub(crate) use rand_hc::Hc128Core as Core;
 /// library versions. For a secure reproducible generator, we recommend use of
 /// the [rand_chacha] crate directly.
 ///
+/// With the `std_rng_serde1` feature, `StdRng` implements `Serialize` and
+/// `Deserialize`, allowing its exact state to be saved and restored (except
+/// on `emscripten`, where a different algorithm is used).
+///
 /// [rand_chacha]: https://crates.io/crates/rand_chacha
 /// [rand issue]: https://github.com/rust-random/rand/issues/932
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std_rng")))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    all(feature = "std_rng_serde1", not(target_os = "emscripten")),
+    derive(Serialize, Deserialize)
+)]
 pub struct StdRng(Rng);
 
 impl RngCore for StdRng {
@@ -99,4 +109,34 @@ mod test {
 
         assert_eq!([x0, x1], target);
     }
+
+    #[test]
+    fn test_stdrng_seed_from_u64() {
+        let mut a = StdRng::seed_from_u64(42);
+        let mut b = StdRng::seed_from_u64(42);
+        let mut c = StdRng::seed_from_u64(43);
+        let mut same = true;
+        for _ in 0..16 {
+            let x = a.next_u64();
+            assert_eq!(x, b.next_u64());
+            same &= x == c.next_u64();
+        }
+        assert!(!same);
+    }
+
+    #[test]
+    #[cfg(feature = "std_rng_serde1")]
+    fn test_serialization_stdrng() {
+        let mut rng = StdRng::seed_from_u64(537);
+        // Serialize mid-block
+        for _ in 0..7 {
+            rng.next_u32();
+        }
+        let mut de_rng: StdRng =
+            bincode::deserialize(&bincode::serialize(&rng).unwrap()).unwrap();
+        assert_eq!(rng, de_rng);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u64(), de_rng.next_u64());
+        }
+    }
 }
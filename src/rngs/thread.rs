@@ -54,6 +54,11 @@ const THREAD_RNG_RESEED_THRESHOLD: u64 = 1024 * 64;
 /// attacks and mis-use (e.g. if somehow weak entropy were supplied initially).
 /// The PRNG algorithms used are assumed to be secure.
 ///
+/// On `wasm32-unknown-unknown` without JavaScript, an entropy source for
+/// [`OsRng`] (and thus `ThreadRng`) may be supplied via the `custom` feature
+/// of `getrandom` and [`getrandom::register_custom_getrandom!`].
+///
+/// [`getrandom::register_custom_getrandom!`]: https://docs.rs/getrandom/0.2/getrandom/macro.register_custom_getrandom.html
 /// [`ReseedingRng`]: crate::rngs::adapter::ReseedingRng
 /// [`StdRng`]: crate::rngs::StdRng
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "std_rng"))))]
@@ -0,0 +1,178 @@
+// Copyright 2021 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature="serde1")] use serde::{Serialize, Deserialize};
+use rand_core::impls::fill_bytes_via_next;
+use rand_core::le::read_u64_into;
+use rand_core::{SeedableRng, RngCore, Error};
+use super::SplitMix64;
+
+/// A xoroshiro128+ random number generator.
+///
+/// The xoroshiro128+ algorithm is not suitable for cryptographic purposes, but
+/// is very fast and has good statistical properties, besides a lack of
+/// linearity in the lowest bits. For this reason `next_u32` uses the high
+/// half of a `u64` output. The period is `2^128 - 1`; use [`jump`] to split
+/// the sequence into non-overlapping substreams.
+///
+/// The algorithm used here is translated from [the `xoroshiro128plus.c`
+/// reference source code](http://xoshiro.di.unimi.it/xoroshiro128plus.c) by
+/// David Blackman and Sebastiano Vigna.
+///
+/// [`jump`]: Xoroshiro128Plus::jump
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature="serde1", derive(Serialize, Deserialize))]
+pub struct Xoroshiro128Plus {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128Plus {
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::Xoroshiro128Plus;
+    ///
+    /// let rng1 = Xoroshiro128Plus::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 2] = [0xdf90_0294_d8f5_54a5, 0x1708_65df_4b32_01fc];
+        let mut s0 = 0;
+        let mut s1 = 0;
+        for &j in &JUMP {
+            for b in 0..64 {
+                if j & (1 << b) != 0 {
+                    s0 ^= self.s0;
+                    s1 ^= self.s1;
+                }
+                self.next_u64();
+            }
+        }
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+impl SeedableRng for Xoroshiro128Plus {
+    type Seed = [u8; 16];
+
+    /// Create a new `Xoroshiro128Plus`.  If `seed` is entirely 0, it will be
+    /// mapped to a different seed.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Xoroshiro128Plus {
+        if seed.iter().all(|&x| x == 0) {
+            return Self::seed_from_u64(0);
+        }
+        let mut state = [0; 2];
+        read_u64_into(&seed, &mut state);
+        Xoroshiro128Plus { s0: state[0], s1: state[1] }
+    }
+
+    /// Create a new `Xoroshiro128Plus` from a `u64` seed.
+    ///
+    /// This uses the SplitMix64 generator internally.
+    fn seed_from_u64(state: u64) -> Self {
+        let mut seed = Self::Seed::default();
+        SplitMix64::new(state).fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl RngCore for Xoroshiro128Plus {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The lowest bits have some linear dependencies, so we use the
+        // upper bits instead.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let r = self.s0.wrapping_add(self.s1);
+        self.s1 ^= self.s0;
+        self.s0 = self.s0.rotate_left(24) ^ self.s1 ^ (self.s1 << 16);
+        self.s1 = self.s1.rotate_left(37);
+        r
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = Xoroshiro128Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoroshiro128plus.c
+        let expected = [
+            3, 412333834243, 2360170716294286339, 9295852285959843169,
+            2797080929874688578, 6019711933173041966, 3076529664176959358,
+            3521761819100106140, 7493067640054542992, 920801338098114767,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump() {
+        let mut rng = Xoroshiro128Plus::from_seed(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        rng.jump();
+        // These values were produced with the reference implementation's
+        // `jump` function.
+        assert_eq!(rng.s0, 0x66fb_d4be_1df0_a7b5);
+        assert_eq!(rng.s1, 0x830c_3ddb_b4aa_3172);
+        let expected = [16863749256561482023, 15988492901402843592, 16860311396414380700];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump_disjoint() {
+        let mut a = Xoroshiro128Plus::seed_from_u64(532);
+        let mut b = a.clone();
+        b.jump();
+        let mut xs = [0u64; 100];
+        for x in xs.iter_mut() {
+            *x = a.next_u64();
+        }
+        for _ in 0..100 {
+            assert!(!xs.contains(&b.next_u64()));
+        }
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut rng = Xoroshiro128Plus::from_seed([0; 16]);
+        assert_ne!(rng.next_u64(), 0);
+        assert_ne!(rng, Xoroshiro128Plus { s0: 0, s1: 0 });
+    }
+}
@@ -10,6 +10,7 @@
 use rand_core::impls::{next_u64_via_u32, fill_bytes_via_next};
 use rand_core::le::read_u32_into;
 use rand_core::{SeedableRng, RngCore, Error};
+use super::SplitMix64;
 
 /// A xoshiro128++ random number generator.
 ///
@@ -43,17 +44,9 @@ impl SeedableRng for Xoshiro128PlusPlus {
     /// Create a new `Xoshiro128PlusPlus` from a `u64` seed.
     ///
     /// This uses the SplitMix64 generator internally.
-    fn seed_from_u64(mut state: u64) -> Self {
-        const PHI: u64 = 0x9e3779b97f4a7c15;
+    fn seed_from_u64(state: u64) -> Self {
         let mut seed = Self::Seed::default();
-        for chunk in seed.as_mut().chunks_mut(8) {
-            state = state.wrapping_add(PHI);
-            let mut z = state;
-            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
-            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
-            z = z ^ (z >> 31);
-            chunk.copy_from_slice(&z.to_le_bytes());
-        }
+        SplitMix64::new(state).fill_bytes(&mut seed);
         Self::from_seed(seed)
     }
 }
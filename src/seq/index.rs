@@ -22,12 +22,13 @@ use crate::distributions::{uniform::SampleUniform, Distribution, Uniform};
 use crate::distributions::WeightedError;
 use crate::Rng;
 
-#[cfg(feature = "serde1")]
+#[cfg(all(feature = "alloc", feature = "serde1"))]
 use serde::{Serialize, Deserialize};
 
 /// A vector of indices.
 ///
 /// Multiple internal representations are possible.
+#[cfg(feature = "alloc")]
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub enum IndexVec {
@@ -37,6 +38,7 @@ pub enum IndexVec {
     USize(Vec<usize>),
 }
 
+#[cfg(feature = "alloc")]
 impl IndexVec {
     /// Returns the number of indices
     #[inline]
@@ -87,6 +89,7 @@ impl IndexVec {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl IntoIterator for IndexVec {
     type Item = usize;
     type IntoIter = IndexVecIntoIter;
@@ -101,6 +104,7 @@ impl IntoIterator for IndexVec {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl PartialEq for IndexVec {
     fn eq(&self, other: &IndexVec) -> bool {
         use self::IndexVec::*;
@@ -117,6 +121,7 @@ impl PartialEq for IndexVec {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Vec<u32>> for IndexVec {
     #[inline]
     fn from(v: Vec<u32>) -> Self {
@@ -124,6 +129,7 @@ impl From<Vec<u32>> for IndexVec {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Vec<usize>> for IndexVec {
     #[inline]
     fn from(v: Vec<usize>) -> Self {
@@ -132,6 +138,7 @@ impl From<Vec<usize>> for IndexVec {
 }
 
 /// Return type of `IndexVec::iter`.
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
 pub enum IndexVecIter<'a> {
     #[doc(hidden)]
@@ -140,6 +147,7 @@ pub enum IndexVecIter<'a> {
     USize(slice::Iter<'a, usize>),
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> Iterator for IndexVecIter<'a> {
     type Item = usize;
 
@@ -161,9 +169,11 @@ impl<'a> Iterator for IndexVecIter<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> ExactSizeIterator for IndexVecIter<'a> {}
 
 /// Return type of `IndexVec::into_iter`.
+#[cfg(feature = "alloc")]
 #[derive(Clone, Debug)]
 pub enum IndexVecIntoIter {
     #[doc(hidden)]
@@ -172,6 +182,7 @@ pub enum IndexVecIntoIter {
     USize(vec::IntoIter<usize>),
 }
 
+#[cfg(feature = "alloc")]
 impl Iterator for IndexVecIntoIter {
     type Item = usize;
 
@@ -194,6 +205,7 @@ impl Iterator for IndexVecIntoIter {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl ExactSizeIterator for IndexVecIntoIter {}
 
 
@@ -215,10 +227,11 @@ impl ExactSizeIterator for IndexVecIntoIter {}
 /// `u64` indices. Because of this we hide the underlying type behind an
 /// abstraction, `IndexVec`.
 ///
-/// If an allocation-free `no_std` function is required, it is suggested
-/// to adapt the internal `sample_floyd` implementation.
+/// If an allocation-free function is required, see [`sample_into`].
 ///
 /// Panics if `amount > length`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub fn sample<R>(rng: &mut R, length: usize, amount: usize) -> IndexVec
 where R: Rng + ?Sized {
     if amount > length {
@@ -258,6 +271,43 @@ where R: Rng + ?Sized {
     }
 }
 
+/// Randomly sample distinct indices from `0..length` into `buf`, without
+/// allocating.
+///
+/// Exactly `min(buf.len(), length)` distinct indices are written to the start
+/// of `buf`, in random order (fully shuffled); this count is returned. The
+/// remainder of `buf` is left unchanged.
+///
+/// This uses Floyd's combination algorithm, thus requires `O(amount^2)` time
+/// where `amount` is the returned count; for large `amount` [`sample`] is
+/// faster. Where `length` fits in a `u32`, results are the same on 32-bit and
+/// 64-bit platforms.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index::sample_into;
+///
+/// let mut buf = [0usize; 4];
+/// let n = sample_into(&mut rand::thread_rng(), 10, &mut buf);
+/// assert_eq!(n, 4);
+/// assert!(buf.iter().all(|&i| i < 10));
+/// ```
+pub fn sample_into<R>(rng: &mut R, length: usize, buf: &mut [usize]) -> usize
+where R: Rng + ?Sized {
+    let amount = buf.len().min(length);
+    for (i, j) in (length - amount..length).enumerate() {
+        let t = super::gen_index(rng, j + 1);
+        if let Some(pos) = buf[..i].iter().position(|&x| x == t) {
+            buf[pos..=i].rotate_right(1);
+            buf[pos] = j;
+        } else {
+            buf[i] = t;
+        }
+    }
+    amount
+}
+
 /// Randomly sample exactly `amount` distinct indices from `0..length`, and
 /// return them in an arbitrary order (there is no guarantee of shuffling or
 /// ordering). The weights are to be provided by the input function `weights`,
@@ -269,7 +319,8 @@ where R: Rng + ?Sized {
 ///
 /// This implementation uses `O(length + amount)` space and `O(length)` time
 /// if the "nightly" feature is enabled, or `O(length)` space and
-/// `O(length + amount * log length)` time otherwise.
+/// `O(length + amount * log length)` time otherwise. For `length` of at most
+/// 16, a simpler method without this overhead is used.
 ///
 /// Panics if `amount > length`.
 #[cfg(feature = "std")]
@@ -282,7 +333,22 @@ where
     F: Fn(usize) -> X,
     X: Into<f64>,
 {
-    if length > (core::u32::MAX as usize) {
+    if length <= SMALL_WEIGHTED_LEN {
+        assert!(amount <= length, "`amount` of samples must be less than or equal to `length`");
+        let mut weights = [0f64; SMALL_WEIGHTED_LEN];
+        for (i, w) in weights[..length].iter_mut().enumerate() {
+            *w = weight(i).into();
+            if !(*w >= 0.) {
+                return Err(WeightedError::InvalidWeight);
+            }
+        }
+        let weights = &mut weights[..length];
+        if weights.iter().sum::<f64>().is_finite() {
+            Ok(sample_weighted_small(rng, weights, amount))
+        } else {
+            sample_efraimidis_spirakis(rng, length as u32, |i| weights[i], amount as u32)
+        }
+    } else if length > (core::u32::MAX as usize) {
         sample_efraimidis_spirakis(rng, length, weight, amount)
     } else {
         assert!(amount <= core::u32::MAX as usize);
@@ -292,6 +358,60 @@ where
     }
 }
 
+/// Maximum `length` for which `sample_weighted` uses `sample_weighted_small`
+#[cfg(feature = "std")]
+const SMALL_WEIGHTED_LEN: usize = 16;
+
+/// Randomly sample exactly `weights.len().min(amount)` distinct indices, in
+/// the order selected, by repeatedly choosing an index with probability
+/// proportional to its weight and removing it. If all remaining weights are
+/// zero, the index is chosen uniformly.
+///
+/// This is equivalent in distribution to `sample_efraimidis_spirakis`, but
+/// avoids its heap of keys. It uses `O(amount)` space and
+/// `O(amount * length)` time, thus is only appropriate for small `length`.
+///
+/// The weights must be non-negative with a finite sum; they are used as
+/// scratch space.
+#[cfg(feature = "std")]
+fn sample_weighted_small<R>(rng: &mut R, weights: &mut [f64], amount: usize) -> IndexVec
+where R: Rng + ?Sized {
+    debug_assert!(weights.len() <= SMALL_WEIGHTED_LEN);
+    let mut order = [0u32; SMALL_WEIGHTED_LEN];
+    for (i, x) in order.iter_mut().enumerate() {
+        *x = i as u32;
+    }
+
+    let mut len = weights.len();
+    let amount = amount.min(len);
+    let mut indices = Vec::with_capacity(amount);
+    for _ in 0..amount {
+        let total: f64 = weights[..len].iter().sum();
+        let chosen = if total > 0. {
+            let mut x = rng.gen::<f64>() * total;
+            // In case of rounding error, fall back to the last index with
+            // positive weight.
+            let mut chosen = 0;
+            for (i, &w) in weights[..len].iter().enumerate() {
+                if w > 0. {
+                    chosen = i;
+                    if x < w {
+                        break;
+                    }
+                    x -= w;
+                }
+            }
+            chosen
+        } else {
+            super::gen_index(rng, len)
+        };
+        indices.push(order[chosen]);
+        len -= 1;
+        weights.swap(chosen, len);
+        order.swap(chosen, len);
+    }
+    IndexVec::from(indices)
+}
 
 /// Randomly sample exactly `amount` distinct indices from `0..length`, and
 /// return them in an arbitrary order (there is no guarantee of shuffling or
@@ -412,6 +532,7 @@ where
 /// The output values are fully shuffled. (Overhead is under 50%.)
 ///
 /// This implementation uses `O(amount)` memory and `O(amount^2)` time.
+#[cfg(feature = "alloc")]
 fn sample_floyd<R>(rng: &mut R, length: u32, amount: u32) -> IndexVec
 where R: Rng + ?Sized {
     // For small amount we use Floyd's fully-shuffled variant. For larger
@@ -456,6 +577,7 @@ where R: Rng + ?Sized {
 /// performance in all cases).
 ///
 /// Set-up is `O(length)` time and memory and shuffling is `O(amount)` time.
+#[cfg(feature = "alloc")]
 fn sample_inplace<R>(rng: &mut R, length: u32, amount: u32) -> IndexVec
 where R: Rng + ?Sized {
     debug_assert!(amount <= length);
@@ -470,12 +592,14 @@ where R: Rng + ?Sized {
     IndexVec::from(indices)
 }
 
+#[cfg(feature = "alloc")]
 trait UInt: Copy + PartialOrd + Ord + PartialEq + Eq + SampleUniform
     + core::hash::Hash + core::ops::AddAssign {
     fn zero() -> Self;
     fn one() -> Self;
     fn as_usize(self) -> usize;
 }
+#[cfg(feature = "alloc")]
 impl UInt for u32 {
     #[inline]
     fn zero() -> Self {
@@ -492,6 +616,7 @@ impl UInt for u32 {
         self as usize
     }
 }
+#[cfg(feature = "alloc")]
 impl UInt for usize {
     #[inline]
     fn zero() -> Self {
@@ -518,6 +643,7 @@ impl UInt for usize {
 ///
 /// This function  is generic over X primarily so that results are value-stable
 /// over 32-bit and 64-bit platforms.
+#[cfg(feature = "alloc")]
 fn sample_rejection<X: UInt, R>(rng: &mut R, length: X, amount: X) -> IndexVec
 where
     R: Rng + ?Sized,
@@ -542,7 +668,7 @@ where
     IndexVec::from(indices)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test {
     use super::*;
 
@@ -586,6 +712,29 @@ mod test {
         assert!(1 << 25 < sum && sum < (1 << 25) * 25);
     }
 
+    #[test]
+    fn test_sample_into() {
+        let mut r = crate::test::rng(494);
+        for &(length, buf_len) in &[(0, 0), (0, 3), (5, 0), (5, 3), (5, 5), (5, 8), (1000, 40)] {
+            let mut buf = [core::usize::MAX; 40];
+            let buf = &mut buf[..buf_len];
+            let n = sample_into(&mut r, length, buf);
+            assert_eq!(n, buf_len.min(length));
+            let mut indices = buf[..n].to_vec();
+            assert!(indices.iter().all(|&i| i < length));
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), n);
+            assert!(buf[n..].iter().all(|&x| x == core::usize::MAX));
+        }
+
+        // Same algorithm as the fully-shuffled variant of `sample_floyd`
+        let mut buf = [0; 20];
+        sample_into(&mut crate::test::rng(495), 1000, &mut buf);
+        let v = sample_floyd(&mut crate::test::rng(495), 1000, 20).into_vec();
+        assert_eq!(&buf[..], &v[..]);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_sample_alg() {
@@ -621,6 +770,22 @@ mod test {
         assert_eq!(v1, v2);
     }
 
+    #[test]
+    fn test_sample_distinct() {
+        let mut r = crate::test::rng(528);
+        // Floyd, in-place and rejection sampling, and edge cases
+        for &(length, amount) in &[(1000, 5), (100, 90), (1 << 20, 600), (3, 3), (10, 0)] {
+            let v = sample(&mut r, length, amount);
+            assert_eq!(v.len(), amount);
+            let mut indices = v.clone().into_vec();
+            assert!(indices.iter().all(|&i| i < length));
+            assert!(v.iter().eq(indices.iter().cloned()));
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), amount);
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_sample_weighted() {
@@ -642,6 +807,41 @@ mod test {
         }
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sample_weighted_small_matches_heap() {
+        // Inclusion frequencies of both algorithms agree with each other
+        let weights = [1., 0., 2., 3., 4., 0.5];
+        const N: usize = 20_000;
+        let mut rng = crate::test::rng(518);
+        let mut small = [0usize; 6];
+        let mut heap = [0usize; 6];
+        for _ in 0..N {
+            let mut scratch = weights;
+            for i in sample_weighted_small(&mut rng, &mut scratch, 3).iter() {
+                small[i] += 1;
+            }
+            let v = sample_efraimidis_spirakis(&mut rng, 6u32, |i| weights[i], 3u32).unwrap();
+            assert_eq!(v.len(), 3);
+            for i in v.iter() {
+                heap[i] += 1;
+            }
+        }
+        assert_eq!(small[1], 0);
+        assert_eq!(heap[1], 0);
+        for (&a, &b) in small.iter().zip(heap.iter()) {
+            let (a, b) = (a as f64 / N as f64, b as f64 / N as f64);
+            assert!((a - b).abs() < 0.02, "{} vs {}", a, b);
+        }
+
+        // Selected indices are distinct, and the zero weight is chosen last
+        let mut scratch = weights;
+        let mut v = sample_weighted_small(&mut rng, &mut scratch, 6).into_vec();
+        assert_eq!(v[5], 1);
+        v.sort_unstable();
+        assert_eq!(v, [0, 1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn value_stability_sample() {
         let do_test = |length, amount, values: &[u32]| {
@@ -13,7 +13,11 @@
 //! *   [`SliceRandom`] slice sampling and mutation
 //! *   [`IteratorRandom`] iterator sampling
 //! *   [`index::sample`] low-level API to choose multiple indices from
-//!     `0..length`
+//!     `0..length`, and [`index::sample_into`] which does not allocate
+//! *   [`random_walk_indices`] a random walk over `0..length`
+//! *   [`random_simplex_point`] a uniformly random point on a simplex
+//! *   [`lazy_permutation`] an allocation-free iterator over `0..n` in random
+//!     order
 //!
 //! Also see:
 //!
@@ -25,12 +29,11 @@
 //! small performance boost in some cases).
 
 
-#[cfg(feature = "alloc")]
-#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod index;
 
 #[cfg(feature = "alloc")] use core::ops::Index;
 
+#[cfg(feature = "alloc")] use alloc::collections::VecDeque;
 #[cfg(feature = "alloc")] use alloc::vec::Vec;
 
 #[cfg(feature = "alloc")]
@@ -181,6 +184,42 @@ pub trait SliceRandom {
             + Clone
             + Default;
 
+    /// Choose `amount` elements independently with replacement, where the
+    /// likelihood of each outcome may be specified.
+    ///
+    /// Each returned element is chosen as by [`choose_weighted`], thus the same
+    /// element may be returned multiple times. A single [`WeightedIndex`] is
+    /// constructed, which is then sampled `amount` times.
+    ///
+    /// For slices of length `n`, complexity is `O(n + amount * log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let choices = [('a', 2), ('b', 1), ('c', 1)];
+    /// let mut rng = thread_rng();
+    /// let picks = choices.sample_weighted_with_replacement(&mut rng, 5, |item| item.1).unwrap();
+    /// assert_eq!(picks.len(), 5);
+    /// ```
+    /// [`choose_weighted`]: SliceRandom::choose_weighted
+    /// [`WeightedIndex`]: crate::distributions::WeightedIndex
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn sample_weighted_with_replacement<R, F, B, X>(
+        &self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<Vec<&Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default;
+
     /// Similar to [`choose_multiple`], but where the likelihood of each element's
     /// inclusion in the output may be specified. The elements are returned in an
     /// arbitrary, unspecified order.
@@ -196,6 +235,8 @@ pub trait SliceRandom {
     /// If the feature is enabled, then for slices of length `n`, the complexity
     /// is `O(n)` space and `O(n)` time. Otherwise, the complexity is `O(n)` space and
     /// `O(n * log amount)` time.
+    /// Slices of at most 16 elements instead use repeated weighted selection
+    /// without a heap, in `O(amount * n)` time.
     ///
     /// # Example
     ///
@@ -265,6 +306,31 @@ pub trait SliceRandom {
         &mut self, rng: &mut R, amount: usize,
     ) -> (&mut [Self::Item], &mut [Self::Item])
     where R: Rng + ?Sized;
+
+    /// Shuffle a slice in place into a single cycle.
+    ///
+    /// This applies Sattolo's algorithm, producing each of the `(n - 1)!`
+    /// cyclic permutations with equal probability. Thus, for slices of at
+    /// least two elements, no element remains at its original position, and
+    /// following `i -> position of the element originally at i` visits every
+    /// index. This is useful e.g. for secret-santa style assignments.
+    ///
+    /// Complexity is `O(n)` where `n = self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    /// use rand::thread_rng;
+    ///
+    /// let mut y = [0, 1, 2, 3, 4];
+    /// y.shuffle_cyclic(&mut thread_rng());
+    /// for (i, &x) in y.iter().enumerate() {
+    ///     assert_ne!(i, x);
+    /// }
+    /// ```
+    fn shuffle_cyclic<R>(&mut self, rng: &mut R)
+    where R: Rng + ?Sized;
 }
 
 /// Extension trait on iterators, providing random sampling methods.
@@ -463,28 +529,61 @@ pub trait IteratorRandom: Iterator + Sized {
     /// For slices, prefer [`SliceRandom::choose_multiple`].
     #[cfg(feature = "alloc")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
-    fn choose_multiple<R>(mut self, rng: &mut R, amount: usize) -> Vec<Self::Item>
+    fn choose_multiple<R>(self, rng: &mut R, amount: usize) -> Vec<Self::Item>
     where R: Rng + ?Sized {
         let mut reservoir = Vec::with_capacity(amount);
-        reservoir.extend(self.by_ref().take(amount));
+        self.choose_multiple_buffered(rng, amount, &mut reservoir);
+        if reservoir.len() < amount {
+            // Don't hang onto extra memory. There is a corner case where
+            // `amount` was much less than `self.len()`.
+            reservoir.shrink_to_fit();
+        }
+        reservoir
+    }
+
+    /// Collects `amount` values at random from the iterator into `scratch`.
+    ///
+    /// This is equivalent to [`choose_multiple`], except that `scratch` is
+    /// cleared and reused instead of allocating a new vector. This avoids
+    /// repeated allocation when sampling in a loop; the capacity of `scratch`
+    /// only grows where necessary to hold `amount` elements.
+    ///
+    /// Complexity is `O(n)` where `n` is the length of the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::IteratorRandom;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut scratch = Vec::new();
+    /// for _ in 0..10 {
+    ///     (1..100).choose_multiple_buffered(&mut rng, 5, &mut scratch);
+    ///     assert_eq!(scratch.len(), 5);
+    /// }
+    /// ```
+    ///
+    /// [`choose_multiple`]: IteratorRandom::choose_multiple
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn choose_multiple_buffered<R>(
+        mut self, rng: &mut R, amount: usize, scratch: &mut Vec<Self::Item>,
+    ) where R: Rng + ?Sized {
+        scratch.clear();
+        scratch.extend(self.by_ref().take(amount));
 
         // Continue unless the iterator was exhausted
         //
         // note: this prevents iterators that "restart" from causing problems.
         // If the iterator stops once, then so do we.
-        if reservoir.len() == amount {
+        if scratch.len() == amount {
             for (i, elem) in self.enumerate() {
                 let k = gen_index(rng, i + 1 + amount);
-                if let Some(slot) = reservoir.get_mut(k) {
+                if let Some(slot) = scratch.get_mut(k) {
                     *slot = elem;
                 }
             }
-        } else {
-            // Don't hang onto extra memory. There is a corner case where
-            // `amount` was much less than `self.len()`.
-            reservoir.shrink_to_fit();
         }
-        reservoir
     }
 }
 
@@ -560,6 +659,25 @@ impl<T> SliceRandom for [T] {
         Ok(&mut self[distr.sample(rng)])
     }
 
+    #[cfg(feature = "alloc")]
+    fn sample_weighted_with_replacement<R, F, B, X>(
+        &self, rng: &mut R, amount: usize, weight: F,
+    ) -> Result<Vec<&Self::Item>, WeightedError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Item) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform
+            + for<'a> ::core::ops::AddAssign<&'a X>
+            + ::core::cmp::PartialOrd<X>
+            + Clone
+            + Default,
+    {
+        use crate::distributions::{Distribution, WeightedIndex};
+        let distr = WeightedIndex::new(self.iter().map(weight))?;
+        Ok((0..amount).map(|_| &self[distr.sample(rng)]).collect())
+    }
+
     #[cfg(feature = "std")]
     fn choose_multiple_weighted<R, F, X>(
         &self, rng: &mut R, amount: usize, weight: F,
@@ -610,6 +728,14 @@ impl<T> SliceRandom for [T] {
         let r = self.split_at_mut(end);
         (r.1, r.0)
     }
+
+    fn shuffle_cyclic<R>(&mut self, rng: &mut R)
+    where R: Rng + ?Sized {
+        for i in (1..self.len()).rev() {
+            // As in `shuffle`, but never swapping an element with itself.
+            self.swap(i, gen_index(rng, i));
+        }
+    }
 }
 
 impl<I> IteratorRandom for I where I: Iterator + Sized {}
@@ -652,6 +778,278 @@ impl<'a, S: Index<usize, Output = T> + ?Sized + 'a, T: 'a> ExactSizeIterator
 }
 
 
+/// Randomly walk over the indices `0..length`, returning `steps` indices.
+///
+/// The walk starts at a uniformly random index; each subsequent index is a
+/// neighbour (`i - 1` or `i + 1`) of the previous one. Neighbours not yet
+/// visited are preferred; only when all neighbours have been visited does
+/// the walk revisit an index. Where `length == 1` the walk stays put.
+///
+/// This may be used e.g. to randomize a path through a linear space such as
+/// cells of a maze row.
+///
+/// # Panics
+///
+/// If `length == 0` and `steps > 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::random_walk_indices;
+///
+/// let walk = random_walk_indices(&mut rand::thread_rng(), 100, 5);
+/// assert_eq!(walk.len(), 5);
+/// for w in walk.windows(2) {
+///     assert_eq!((w[0] as isize - w[1] as isize).abs(), 1);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn random_walk_indices<R>(rng: &mut R, length: usize, steps: usize) -> Vec<usize>
+where R: Rng + ?Sized {
+    use alloc::collections::BTreeSet;
+
+    let mut walk = Vec::with_capacity(steps);
+    if steps == 0 {
+        return walk;
+    }
+    assert!(length > 0, "cannot walk over an empty range");
+    let mut visited = BTreeSet::new();
+    let mut pos = gen_index(rng, length);
+    walk.push(pos);
+    visited.insert(pos);
+
+    while walk.len() < steps {
+        let mut neighbours = [0; 2];
+        let mut n = 0;
+        if pos > 0 {
+            neighbours[n] = pos - 1;
+            n += 1;
+        }
+        if pos + 1 < length {
+            neighbours[n] = pos + 1;
+            n += 1;
+        }
+        let neighbours = &neighbours[..n];
+
+        let mut fresh = [0; 2];
+        let mut m = 0;
+        for &i in neighbours {
+            if !visited.contains(&i) {
+                fresh[m] = i;
+                m += 1;
+            }
+        }
+
+        pos = if m > 0 {
+            *fresh[..m].choose(rng).unwrap()
+        } else {
+            // Stuck: revisit (or stay put if there are no neighbours)
+            neighbours.choose(rng).cloned().unwrap_or(pos)
+        };
+        walk.push(pos);
+        visited.insert(pos);
+    }
+    walk
+}
+
+/// Sample a uniformly random point on the `(n - 1)`-simplex.
+///
+/// The result holds `n` non-negative coordinates summing to 1 (up to
+/// rounding), i.e. a random probability vector. This is computed as the
+/// gaps between `n - 1` sorted uniform samples from `[0, 1)`, and is
+/// equivalent to a Dirichlet distribution with all parameters 1.
+///
+/// # Panics
+///
+/// If `n == 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::random_simplex_point;
+///
+/// let p = random_simplex_point(&mut rand::thread_rng(), 3);
+/// assert_eq!(p.len(), 3);
+/// assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn random_simplex_point<R>(rng: &mut R, n: usize) -> Vec<f64>
+where R: Rng + ?Sized {
+    assert!(n > 0, "the simplex must have at least one vertex");
+    let mut cuts: Vec<f64> = (1..n).map(|_| rng.gen()).collect();
+    cuts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    cuts.push(1.0);
+
+    let mut last = 0.0;
+    for x in cuts.iter_mut() {
+        let cut = *x;
+        *x = cut - last;
+        last = cut;
+    }
+    cuts
+}
+
+/// Return a random element of `deque`, or `None` if it is empty.
+///
+/// The element is selected uniformly by index, regardless of how the deque's
+/// contents are arranged in its ring buffer. This uses the same index sampling
+/// as [`SliceRandom::choose`], thus for a deque which is contiguous the result
+/// matches `choose` on the first slice of [`VecDeque::as_slices`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use rand::seq::choose_from_deque;
+///
+/// let deque: VecDeque<u32> = (1..=5).collect();
+/// let x = choose_from_deque(&mut rand::thread_rng(), &deque).unwrap();
+/// assert!(deque.contains(x));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn choose_from_deque<'a, R, T>(rng: &mut R, deque: &'a VecDeque<T>) -> Option<&'a T>
+where R: Rng + ?Sized {
+    if deque.is_empty() {
+        None
+    } else {
+        deque.get(gen_index(rng, deque.len()))
+    }
+}
+
+/// Sample a grid of `rows` rows by `cols` columns of booleans, where each cell
+/// is independently `true` with probability `p`.
+///
+/// All cells are sampled from a single [`Bernoulli`] distribution, in row-major
+/// order. This is useful e.g. for percolation simulations.
+///
+/// # Panics
+///
+/// If `p < 0` or `p > 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::bernoulli_grid;
+///
+/// let grid = bernoulli_grid(&mut rand::thread_rng(), 3, 4, 0.6);
+/// assert_eq!(grid.len(), 3);
+/// assert!(grid.iter().all(|row| row.len() == 4));
+/// ```
+///
+/// [`Bernoulli`]: crate::distributions::Bernoulli
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub fn bernoulli_grid<R>(rng: &mut R, rows: usize, cols: usize, p: f64) -> Vec<Vec<bool>>
+where R: Rng + ?Sized {
+    use crate::distributions::{Bernoulli, Distribution};
+    let d = match Bernoulli::new(p) {
+        Ok(d) => d,
+        Err(_) => panic!("p={:?} is outside range [0.0, 1.0]", p),
+    };
+    (0..rows)
+        .map(|_| d.sample_iter(&mut *rng).take(cols).collect())
+        .collect()
+}
+
+/// Iterate over the indices `0..n` in a random order, without allocating.
+///
+/// Unlike shuffling a vector of indices, this uses constant memory, and is
+/// thus suitable for visiting a large index space in random order. The order
+/// is given by a randomly keyed bijection on `0..2^k`, where `2^k` is the
+/// smallest power of two no less than `n`; values outside `0..n` are skipped
+/// (at most doubling the work). Each index is yielded exactly once.
+///
+/// The permutation is **not** uniformly distributed over all `n!` orderings:
+/// the bijection (a few rounds of multiply, xor-shift and add modulo `2^k`)
+/// mixes well, but is selected from far fewer keys. Use
+/// [`SliceRandom::shuffle`] where uniformity is required.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::lazy_permutation;
+///
+/// let mut order: Vec<usize> = lazy_permutation(&mut rand::thread_rng(), 5).collect();
+/// order.sort();
+/// assert_eq!(order, [0, 1, 2, 3, 4]);
+/// ```
+pub fn lazy_permutation<R>(rng: &mut R, n: usize) -> impl Iterator<Item = usize>
+where R: Rng + ?Sized {
+    LazyPermutation::new(rng, n)
+}
+
+const LAZY_PERMUTATION_ROUNDS: usize = 3;
+
+#[derive(Debug)]
+struct LazyPermutation {
+    mask: u64,
+    shift: u32,
+    mul: [u64; LAZY_PERMUTATION_ROUNDS],
+    add: [u64; LAZY_PERMUTATION_ROUNDS],
+    n: u64,
+    counter: u64,
+    remaining: usize,
+}
+
+impl LazyPermutation {
+    fn new<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Self {
+        let bits = if n <= 1 { 0 } else { 64 - (n as u64 - 1).leading_zeros() };
+        let mask = if bits == 64 { core::u64::MAX } else { (1 << bits) - 1 };
+        let mut mul = [0; LAZY_PERMUTATION_ROUNDS];
+        let mut add = [0; LAZY_PERMUTATION_ROUNDS];
+        for (m, a) in mul.iter_mut().zip(add.iter_mut()) {
+            // Odd multipliers are invertible modulo a power of two
+            *m = (rng.next_u64() | 1) & mask;
+            *a = rng.next_u64() & mask;
+        }
+        LazyPermutation {
+            mask,
+            shift: bits / 2 + 1,
+            mul,
+            add,
+            n: n as u64,
+            counter: 0,
+            remaining: n,
+        }
+    }
+
+    // A bijection on `0..=mask`, since each step is.
+    fn permute(&self, mut x: u64) -> u64 {
+        for (&m, &a) in self.mul.iter().zip(self.add.iter()) {
+            x = x.wrapping_mul(m) & self.mask;
+            x ^= x >> self.shift;
+            x = x.wrapping_add(a) & self.mask;
+        }
+        x
+    }
+}
+
+impl Iterator for LazyPermutation {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // While indices remain, `counter` has not yet covered `0..=mask`.
+        loop {
+            let x = self.permute(self.counter);
+            self.counter = self.counter.wrapping_add(1);
+            if x < self.n {
+                self.remaining -= 1;
+                return Some(x as usize);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
 // Sample a number uniformly between 0 and `ubound`. Uses 32-bit sampling where
 // possible, primarily in order to produce the same output on 32-bit and 64-bit
 // platforms.
@@ -671,6 +1069,32 @@ mod test {
     #[cfg(feature = "alloc")] use crate::Rng;
     #[cfg(all(feature = "alloc", not(feature = "std")))] use alloc::vec::Vec;
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_lazy_permutation() {
+        let mut r = crate::test::rng(532);
+        for &n in &[0usize, 1, 2, 7, 64, 1000] {
+            let mut v: Vec<usize> = lazy_permutation(&mut r, n).collect();
+            v.sort_unstable();
+            assert_eq!(v, (0..n).collect::<Vec<usize>>());
+        }
+
+        let iter = lazy_permutation(&mut r, 10);
+        assert_eq!(iter.size_hint(), (10, Some(10)));
+
+        // Not the identity permutation
+        let v: Vec<usize> = lazy_permutation(&mut r, 1000).take(10).collect();
+        assert_ne!(v, (0..10).collect::<Vec<usize>>());
+
+        // Large `n`: the first outputs are distinct and in range
+        let n = core::usize::MAX / 3;
+        let mut v: Vec<usize> = lazy_permutation(&mut r, n).take(1000).collect();
+        assert!(v.iter().all(|&i| i < n));
+        v.sort_unstable();
+        v.dedup();
+        assert_eq!(v.len(), 1000);
+    }
+
     #[test]
     fn test_slice_choose() {
         let mut r = crate::test::rng(107);
@@ -1020,6 +1444,97 @@ mod test {
         assert!(res.1[0] == 1 || res.1[1] == 2 || res.1[2] == 3);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_slice_choose_multiple_distinct() {
+        let mut r = crate::test::rng(527);
+        let items: Vec<usize> = (0..10).collect();
+        let mut counts = [0usize; 10];
+        for _ in 0..2000 {
+            let mut seen = [false; 10];
+            let chosen: Vec<&usize> = items.choose_multiple(&mut r, 3).collect();
+            assert_eq!(chosen.len(), 3);
+            for &&x in &chosen {
+                assert!(!seen[x]);
+                seen[x] = true;
+                counts[x] += 1;
+            }
+        }
+        // Each element is selected with probability 3/10
+        assert!(counts.iter().all(|&c| c > 520 && c < 680));
+
+        // Asking for at least the length returns every element
+        for &amount in &[10, 20] {
+            let mut all: Vec<usize> = items.choose_multiple(&mut r, amount).cloned().collect();
+            all.sort_unstable();
+            assert_eq!(all, items);
+        }
+    }
+
+    #[test]
+    fn test_partial_shuffle_partition() {
+        let mut r = crate::test::rng(526);
+        let mut counts = [0usize; 20];
+        for _ in 0..2000 {
+            let mut v = [0usize; 20];
+            for (i, x) in v.iter_mut().enumerate() {
+                *x = i;
+            }
+            let (shuffled, rest) = v.partial_shuffle(&mut r, 5);
+            assert_eq!((shuffled.len(), rest.len()), (5, 15));
+            for &x in shuffled.iter() {
+                counts[x] += 1;
+            }
+
+            // Together, the two slices hold every element exactly once
+            let mut seen = [false; 20];
+            for &x in shuffled.iter().chain(rest.iter()) {
+                assert!(!seen[x]);
+                seen[x] = true;
+            }
+        }
+        // Each element is selected with probability 5/20
+        assert!(counts.iter().all(|&c| c > 400 && c < 600));
+    }
+
+    #[test]
+    fn test_shuffle_cyclic() {
+        let mut r = crate::test::rng(508);
+        let mut empty: [usize; 0] = [];
+        empty.shuffle_cyclic(&mut r);
+        let mut one = [0];
+        one.shuffle_cyclic(&mut r);
+        assert_eq!(one, [0]);
+
+        for len in 2..20 {
+            let mut v = [0usize; 20];
+            let v = &mut v[..len];
+            for (i, x) in v.iter_mut().enumerate() {
+                *x = i;
+            }
+            v.shuffle_cyclic(&mut r);
+            // Following the permutation from 0 must visit all indices
+            let mut i = 0;
+            for step in 1..=len {
+                assert_ne!(v[i], i);
+                i = v[i];
+                assert_eq!(i == 0, step == len);
+            }
+        }
+
+        // All 3! = 6 cycles of length 4 are equally likely
+        let mut counts = [0i32; 256];
+        for _ in 0..6000 {
+            let mut arr = [0usize, 1, 2, 3];
+            arr.shuffle_cyclic(&mut r);
+            counts[arr.iter().fold(0, |acc, &x| acc * 4 + x)] += 1;
+        }
+        assert_eq!(counts.iter().filter(|&&c| c > 0).count(), 6);
+        for &c in counts.iter().filter(|&&c| c > 0) {
+            assert!((c - 1000).abs() < 120, "{}", c);
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_sample_iter() {
@@ -1113,6 +1628,65 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_choose_weighted_dominant() {
+        let mut r = crate::test::rng(525);
+        let items = ['a', 'b', 'c'];
+        let weights = [1, 1, 8];
+        const N: usize = 10_000;
+        let count = (0..N)
+            .filter(|_| {
+                let c = items.choose_weighted(&mut r, |c| weights[(*c as u8 - b'a') as usize]);
+                c == Ok(&'c')
+            })
+            .count();
+        assert!((count as f64 / N as f64 - 0.8).abs() < 0.02);
+
+        assert_eq!(
+            items.choose_weighted(&mut r, |_| 0.0),
+            Err(WeightedError::AllWeightsZero)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_sample_weighted_with_replacement() {
+        let mut r = crate::test::rng(538);
+        let items = [0usize, 1, 2, 3];
+        let weights = [1, 2, 3, 4];
+        const N: usize = 20_000;
+        let picks = items
+            .sample_weighted_with_replacement(&mut r, N, |i| weights[*i])
+            .unwrap();
+        assert_eq!(picks.len(), N);
+        let mut counts = [0usize; 4];
+        for &&i in &picks {
+            counts[i] += 1;
+        }
+        for (&c, &w) in counts.iter().zip(weights.iter()) {
+            assert!((c as f64 / N as f64 - w as f64 / 10.).abs() < 0.015);
+        }
+
+        // Picks repeat, even with more picks than items
+        let picks = ['x'].sample_weighted_with_replacement(&mut r, 3, |_| 1).unwrap();
+        assert_eq!(picks, [&'x', &'x', &'x']);
+
+        assert_eq!(
+            items.sample_weighted_with_replacement(&mut r, 0, |_| 1).unwrap().len(),
+            0
+        );
+        assert_eq!(
+            items.sample_weighted_with_replacement(&mut r, 2, |_| 0),
+            Err(WeightedError::AllWeightsZero)
+        );
+        let empty: [u8; 0] = [];
+        assert_eq!(
+            empty.sample_weighted_with_replacement(&mut r, 2, |_| 1),
+            Err(WeightedError::NoItem)
+        );
+    }
+
     #[test]
     fn value_stability_choose() {
         fn choose<I: Iterator<Item = u32>>(iter: I) -> Option<u32> {
@@ -1356,4 +1930,170 @@ mod test {
             .map(|(a, b)| (a - b).abs());
         assert!(!diffs.any(|deviation| deviation > 100));
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_random_walk_indices() {
+        let mut r = crate::test::rng(488);
+        assert!(random_walk_indices(&mut r, 0, 0).is_empty());
+        assert_eq!(random_walk_indices(&mut r, 1, 3), [0, 0, 0]);
+
+        for &(length, steps) in &[(2, 10), (5, 50), (1000, 20)] {
+            let walk = random_walk_indices(&mut r, length, steps);
+            assert_eq!(walk.len(), steps);
+            assert!(walk.iter().all(|&i| i < length));
+            for w in walk.windows(2) {
+                assert!(w[0] + 1 == w[1] || w[1] + 1 == w[0]);
+            }
+        }
+
+        // Short walks away from the boundary never repeat
+        for _ in 0..100 {
+            let walk = random_walk_indices(&mut r, 1_000_000, 20);
+            if walk[0] >= 20 && walk[0] + 20 <= 1_000_000 {
+                let mut sorted = walk.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+                assert_eq!(sorted.len(), walk.len());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn test_random_walk_indices_empty() {
+        random_walk_indices(&mut crate::test::rng(489), 0, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_random_simplex_point() {
+        let mut r = crate::test::rng(512);
+        assert_eq!(random_simplex_point(&mut r, 1), [1.0]);
+        for &n in &[2, 3, 10, 100] {
+            let p = random_simplex_point(&mut r, n);
+            assert_eq!(p.len(), n);
+            assert!(p.iter().all(|&x| x >= 0.0));
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+
+        // Each coordinate is Beta(1, n - 1) distributed, with mean 1 / n
+        let mut sums = [0.0; 4];
+        for _ in 0..10_000 {
+            for (sum, x) in sums.iter_mut().zip(random_simplex_point(&mut r, 4)) {
+                *sum += x;
+            }
+        }
+        for &sum in &sums {
+            assert!((sum / 10_000.0 - 0.25).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_choose_from_deque() {
+        let mut r = crate::test::rng(529);
+        let mut deque = VecDeque::with_capacity(8);
+        assert_eq!(choose_from_deque(&mut r, &deque), None::<&u32>);
+
+        // Wrap around the end of the ring buffer
+        deque.extend(0..6u32);
+        deque.drain(..4);
+        deque.extend(6..10);
+        let (front, back) = deque.as_slices();
+        assert!(!front.is_empty() && !back.is_empty());
+
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            seen[*choose_from_deque(&mut r, &deque).unwrap() as usize] = true;
+        }
+        assert_eq!(seen, [false, false, false, false, true, true, true, true, true, true]);
+
+        let contiguous: VecDeque<u32> = (0..10).collect();
+        let slice: Vec<u32> = (0..10).collect();
+        let (mut r1, mut r2) = (crate::test::rng(530), crate::test::rng(530));
+        for _ in 0..10 {
+            assert_eq!(choose_from_deque(&mut r1, &contiguous), slice.choose(&mut r2));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bernoulli_grid() {
+        let mut r = crate::test::rng(527);
+        assert!(bernoulli_grid(&mut r, 0, 5, 0.5).is_empty());
+        assert!(bernoulli_grid(&mut r, 3, 0, 0.5).iter().all(|row| row.is_empty()));
+        assert!(bernoulli_grid(&mut r, 4, 4, 0.0).iter().flatten().all(|&x| !x));
+        assert!(bernoulli_grid(&mut r, 4, 4, 1.0).iter().flatten().all(|&x| x));
+
+        let grid = bernoulli_grid(&mut r, 100, 200, 0.3);
+        assert_eq!(grid.len(), 100);
+        assert!(grid.iter().all(|row| row.len() == 200));
+        let count = grid.iter().flatten().filter(|&&x| x).count();
+        assert!((count as f64 / 20_000.0 - 0.3).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn test_bernoulli_grid_invalid() {
+        bernoulli_grid(&mut crate::test::rng(528), 2, 2, 1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn test_random_simplex_point_empty() {
+        random_simplex_point(&mut crate::test::rng(512), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_iterator_choose_multiple_buffered() {
+        let slice = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let mut r = crate::test::rng(502);
+        let mut scratch = Vec::new();
+        for amount in (0..20).chain(0..20) {
+            slice.iter().choose_multiple_buffered(&mut r, amount, &mut scratch);
+            assert_eq!(scratch.len(), amount.min(slice.len()));
+            let mut sorted = scratch.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), scratch.len());
+            assert!(scratch.capacity() <= 2 * 20);
+        }
+
+        // Matches the allocating version
+        let mut r1 = crate::test::rng(503);
+        let mut r2 = crate::test::rng(503);
+        let mut scratch = Vec::new();
+        (0..1000).choose_multiple_buffered(&mut r1, 10, &mut scratch);
+        assert_eq!(scratch, (0..1000).choose_multiple(&mut r2, 10));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_iterator_choose_multiple_uniform() {
+        // Elements are included with probability 10 / 1000 each, independent
+        // of their position in the iterator.
+        const TRIALS: usize = 5000;
+        let mut r = crate::test::rng(524);
+        let mut counts = [0usize; 1000];
+        for _ in 0..TRIALS {
+            let sample = (0..1000).choose_multiple(&mut r, 10);
+            assert_eq!(sample.len(), 10);
+            for i in sample {
+                counts[i] += 1;
+            }
+        }
+        // Each count has mean 50 and standard deviation about 7
+        assert!(counts.iter().all(|&c| (10..=90).contains(&c)));
+        // Each block of 100 has mean 5000 and standard deviation about 70
+        for block in counts.chunks(100) {
+            let sum: usize = block.iter().sum();
+            assert!((4650..=5350).contains(&sum), "{}", sum);
+        }
+    }
 }